@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use crate::filesystem::FileSystem;
+
+// A file or symlink about to be removed, measured before the removal
+#[derive(Clone)]
+pub struct FileUsage {
+    pub path: PathBuf,
+    // (device, inode), None where the platform does not tell
+    pub key: Option<(u64, u64)>,
+    pub links: u64,
+    pub apparent: u64,
+    pub allocated: u64,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct SpaceUsage {
+    // file sizes, every hard-linked file counted once
+    pub apparent: u64,
+    // blocks the removed files occupied
+    pub allocated: u64,
+    // blocks that were actually freed, inodes whose last link was removed
+    pub reclaimed: u64,
+}
+
+struct InodeUsage {
+    links: u64,
+    removed: u64,
+    allocated: u64,
+    freed: bool,
+}
+
+#[derive(Default)]
+struct AccountingState {
+    inodes: HashMap<(u64, u64), InodeUsage>,
+    untracked: SpaceUsage,
+}
+
+// Shared by every rule of a run, so a file hard-linked from two rules is counted once
+#[derive(Default)]
+pub struct SpaceAccounting {
+    state: Mutex<AccountingState>,
+}
+
+impl SpaceAccounting {
+    // `freed` is false for quarantine, a moved file still occupies its blocks
+    pub fn record(&self, usage: &FileUsage, freed: bool) {
+        let mut state = self.state.lock().unwrap();
+        let key = match usage.key {
+            Some(key) => key,
+            None => {
+                state.untracked.apparent += usage.apparent;
+                state.untracked.allocated += usage.allocated;
+                if freed {
+                    state.untracked.reclaimed += usage.allocated;
+                }
+                return;
+            }
+        };
+        if !state.inodes.contains_key(&key) {
+            state.untracked.apparent += usage.apparent;
+        }
+        let inode = state.inodes.entry(key).or_insert(InodeUsage {
+            links: usage.links,
+            removed: 0,
+            allocated: usage.allocated,
+            freed,
+        });
+        // the link count drops while links are removed, the highest one seen is the original
+        inode.links = inode.links.max(usage.links);
+        inode.removed += 1;
+        inode.freed &= freed;
+    }
+
+    pub fn usage(&self) -> SpaceUsage {
+        let state = self.state.lock().unwrap();
+        let mut usage = state.untracked;
+        for inode in state.inodes.values() {
+            usage.allocated += inode.allocated;
+            if inode.freed && inode.removed >= inode.links {
+                usage.reclaimed += inode.allocated;
+            }
+        }
+        usage
+    }
+}
+
+// Every file and symlink at or below the path, directories themselves are not counted
+pub fn collect_usage(filesystem: &dyn FileSystem, path: &Path, usages: &mut Vec<FileUsage>) {
+    let metadata = match filesystem.symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return,
+    };
+    if metadata.is_dir() {
+        if let Ok(entries) = filesystem.read_dir(path) {
+            for entry in entries {
+                collect_usage(filesystem, &entry, usages);
+            }
+        }
+        return;
+    }
+    usages.push(FileUsage {
+        path: path.to_path_buf(),
+        key: metadata.device.zip(metadata.inode),
+        links: metadata.links.max(1),
+        apparent: metadata.len,
+        allocated: metadata.allocated,
+    });
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+use database::structures::{CleanerSkip, CleanerSkipReason};
+use crate::filesystem::FileSystem;
+
+// Checks that a matched path is reached without passing through a symlink, junction or
+// another filesystem. Components above the user's home directory (or the filesystem root
+// for paths outside of it) are trusted, so a symlinked /home does not block everything.
+pub fn check_path(filesystem: &dyn FileSystem, path: &Path) -> Option<CleanerSkip> {
+    let anchor = get_anchor(path);
+    let anchor_device = filesystem.symlink_metadata(&anchor).ok().and_then(|metadata| metadata.device);
+
+    for ancestor in path.ancestors() {
+        if ancestor == anchor || !ancestor.starts_with(&anchor) {
+            break;
+        }
+        let metadata = match filesystem.symlink_metadata(ancestor) {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        if metadata.is_symlink() {
+            return Some(skip(ancestor, CleanerSkipReason::Symlink));
+        }
+        if anchor_device.is_some() && metadata.device != anchor_device {
+            return Some(skip(ancestor, CleanerSkipReason::MountPoint));
+        }
+    }
+    None
+}
+
+// Looks for a filesystem mounted somewhere inside the directory, remove_dir_all would descend into it
+pub fn check_directory(filesystem: &dyn FileSystem, path: &Path) -> Option<CleanerSkip> {
+    let metadata = filesystem.symlink_metadata(path).ok()?;
+    let device = metadata.device?;
+    find_mount_point(filesystem, path, device).map(|mount_point| skip(&mount_point, CleanerSkipReason::MountPoint))
+}
+
+fn find_mount_point(filesystem: &dyn FileSystem, path: &Path, device: u64) -> Option<PathBuf> {
+    for entry in filesystem.read_dir(path).ok()? {
+        let metadata = match filesystem.symlink_metadata(&entry) {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        if !metadata.is_dir() {
+            continue;
+        }
+        if metadata.device != Some(device) {
+            return Some(entry);
+        }
+        if let Some(mount_point) = find_mount_point(filesystem, &entry, device) {
+            return Some(mount_point);
+        }
+    }
+    None
+}
+
+fn get_anchor(path: &Path) -> PathBuf {
+    for ancestor in path.ancestors() {
+        let parent_name = ancestor.parent()
+            .and_then(|parent| parent.file_name())
+            .map(|name| name.to_string_lossy().to_lowercase());
+        if matches!(parent_name.as_deref(), Some("users") | Some("home")) {
+            return ancestor.to_path_buf();
+        }
+    }
+    path.ancestors().last().map(Path::to_path_buf).unwrap_or_default()
+}
+
+fn skip(path: &Path, reason: CleanerSkipReason) -> CleanerSkip {
+    CleanerSkip {
+        path: path.to_string_lossy().to_string(),
+        reason,
+    }
+}
@@ -0,0 +1,106 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use glob::{MatchOptions, Pattern};
+use database::utils::get_winbooster_directory;
+use crate::filesystem::FileSystem;
+
+pub fn get_exclusions_file() -> PathBuf {
+    get_winbooster_directory().join("exclusions.txt")
+}
+
+pub fn load_exclusions() -> Vec<String> {
+    match fs::read_to_string(get_exclusions_file()) {
+        Ok(content) => content.lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+pub fn save_exclusions(exclusions: &[String]) -> io::Result<()> {
+    let file = get_exclusions_file();
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(file, exclusions.join("\n") + "\n")
+}
+
+pub fn add_exclusion(pattern: &str) -> io::Result<()> {
+    Pattern::new(pattern).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error.msg))?;
+    let mut exclusions = load_exclusions();
+    if !exclusions.iter().any(|exclusion| exclusion == pattern) {
+        exclusions.push(pattern.to_string());
+    }
+    save_exclusions(&exclusions)
+}
+
+pub fn remove_exclusion(pattern: &str) -> io::Result<bool> {
+    let mut exclusions = load_exclusions();
+    let count = exclusions.len();
+    exclusions.retain(|exclusion| exclusion != pattern);
+    if exclusions.len() == count {
+        return Ok(false);
+    }
+    save_exclusions(&exclusions)?;
+    Ok(true)
+}
+
+pub fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>, String> {
+    let mut compiled = vec![];
+    for pattern in patterns {
+        match Pattern::new(pattern) {
+            Ok(pattern) => compiled.push(pattern),
+            Err(_) => return Err(pattern.clone()),
+        }
+    }
+    Ok(compiled)
+}
+
+// The global exclusions follow the platform, a rule's own ones use matching::match_options of the rule
+pub fn global_match_options() -> MatchOptions {
+    MatchOptions {
+        case_sensitive: !cfg!(windows),
+        ..MatchOptions::new()
+    }
+}
+
+// A path is excluded when it, one of its parents or, for directories, anything inside it matches.
+// Patterns without a path separator are matched against the file name only.
+pub fn is_excluded(filesystem: &dyn FileSystem, path: &Path, patterns: &[Pattern], options: MatchOptions) -> bool {
+    if patterns.is_empty() {
+        return false;
+    }
+    if path.ancestors().any(|ancestor| matches_any(ancestor, patterns, options)) {
+        return true;
+    }
+    contains_excluded(filesystem, path, patterns, options)
+}
+
+fn contains_excluded(filesystem: &dyn FileSystem, path: &Path, patterns: &[Pattern], options: MatchOptions) -> bool {
+    let is_dir = filesystem.symlink_metadata(path).map(|metadata| metadata.is_dir()).unwrap_or(false);
+    if !is_dir {
+        return false;
+    }
+    match filesystem.read_dir(path) {
+        Ok(entries) => entries.iter().any(|entry_path| {
+            matches_any(entry_path, patterns, options) || contains_excluded(filesystem, entry_path, patterns, options)
+        }),
+        Err(_) => false,
+    }
+}
+
+fn matches_any(path: &Path, patterns: &[Pattern], match_options: MatchOptions) -> bool {
+    patterns.iter().any(|pattern| {
+        if pattern.as_str().contains(['/', '\\']) {
+            pattern.matches_path_with(path, match_options)
+        }
+        else {
+            match path.file_name() {
+                Some(file_name) => pattern.matches_with(&file_name.to_string_lossy(), match_options),
+                None => false,
+            }
+        }
+    })
+}
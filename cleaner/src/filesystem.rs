@@ -0,0 +1,173 @@
+use std::fs::{self, Metadata};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use glob::{glob_with, MatchOptions, PatternError};
+use crate::shred::{overwrite_path, Overwritten};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum FileKind {
+    File,
+    Directory,
+    // symlinks, and on Windows every reparse point such as junctions and mounted folders
+    Symlink,
+}
+
+#[derive(Clone, Copy)]
+pub struct FileMetadata {
+    pub kind: FileKind,
+    pub len: u64,
+    pub modified: Option<SystemTime>,
+    // filesystem the entry lives on, None where the platform does not tell
+    pub device: Option<u64>,
+    // inode number and hard link count, None and 1 where the platform does not tell
+    pub inode: Option<u64>,
+    pub links: u64,
+    // bytes the entry occupies on disk, less than len for sparse files
+    pub allocated: u64,
+}
+
+impl FileMetadata {
+    pub fn is_dir(&self) -> bool {
+        self.kind == FileKind::Directory
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.kind == FileKind::File
+    }
+
+    pub fn is_symlink(&self) -> bool {
+        self.kind == FileKind::Symlink
+    }
+}
+
+// A directory glob could not read while expanding a pattern
+pub struct GlobFailure {
+    pub path: PathBuf,
+    pub error: io::Error,
+}
+
+pub type GlobPaths<'a> = Box<dyn Iterator<Item = Result<PathBuf, GlobFailure>> + 'a>;
+
+// Every disk access of the cleaner goes through this, so rules can be run against
+// memory_filesystem::MemoryFileSystem instead of a real disk
+pub trait FileSystem: Send + Sync {
+    // does not follow a symlink at the path itself
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata>;
+    // follows symlinks
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata>;
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+    // absolute path with symlinks resolved, fails if the path does not exist
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
+    fn glob(&self, pattern: &str, options: MatchOptions) -> Result<GlobPaths<'_>, PatternError>;
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+    fn remove_dir(&self, path: &Path) -> io::Result<()>;
+    // stops at the first failure like std::fs::remove_dir_all, what was removed before stays removed
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()>;
+    // see shred::overwrite_path
+    fn overwrite(&self, path: &Path, passes: u32) -> io::Result<Overwritten>;
+}
+
+pub struct RealFileSystem;
+
+impl FileSystem for RealFileSystem {
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        fs::symlink_metadata(path).map(|metadata| convert_metadata(&metadata))
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        fs::metadata(path).map(|metadata| convert_metadata(&metadata))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        Ok(fs::read_dir(path)?.flatten().map(|entry| entry.path()).collect())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        fs::canonicalize(path)
+    }
+
+    fn glob(&self, pattern: &str, options: MatchOptions) -> Result<GlobPaths<'_>, PatternError> {
+        let paths = glob_with(pattern, options)?;
+        Ok(Box::new(paths.map(|result| result.map_err(|error| GlobFailure {
+            path: error.path().to_path_buf(),
+            error: error.into_error(),
+        }))))
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        fs::remove_file(path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        fs::remove_dir(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::remove_dir_all(path)
+    }
+
+    fn overwrite(&self, path: &Path, passes: u32) -> io::Result<Overwritten> {
+        overwrite_path(path, passes)
+    }
+}
+
+pub(crate) fn convert_metadata(metadata: &Metadata) -> FileMetadata {
+    let kind = if is_link(metadata) {
+        FileKind::Symlink
+    }
+    else if metadata.is_dir() {
+        FileKind::Directory
+    }
+    else {
+        FileKind::File
+    };
+    let (inode, links, allocated) = get_inode(metadata);
+    FileMetadata {
+        kind,
+        len: metadata.len(),
+        modified: metadata.modified().ok(),
+        device: get_device(metadata),
+        inode,
+        links,
+        allocated,
+    }
+}
+
+fn is_link(metadata: &Metadata) -> bool {
+    if metadata.file_type().is_symlink() {
+        return true;
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        // FILE_ATTRIBUTE_REPARSE_POINT, covers junctions and mounted folders
+        if metadata.file_attributes() & 0x400 != 0 {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(unix)]
+fn get_device(metadata: &Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.dev())
+}
+
+#[cfg(not(unix))]
+fn get_device(_metadata: &Metadata) -> Option<u64> {
+    None
+}
+
+#[cfg(unix)]
+fn get_inode(metadata: &Metadata) -> (Option<u64>, u64, u64) {
+    use std::os::unix::fs::MetadataExt;
+    // st_blocks is always counted in 512-byte units
+    (Some(metadata.ino()), metadata.nlink(), metadata.blocks() * 512)
+}
+
+#[cfg(not(unix))]
+fn get_inode(metadata: &Metadata) -> (Option<u64>, u64, u64) {
+    (None, 1, metadata.len())
+}
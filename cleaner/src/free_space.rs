@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Free space of one filesystem, sampled before and after a run.
+// None where it could not be read.
+#[derive(Clone)]
+pub struct MountSpace {
+    pub mount: PathBuf,
+    pub before: Option<u64>,
+    pub after: Option<u64>,
+}
+
+impl MountSpace {
+    // Negative when the filesystem filled up during the run
+    pub fn freed(&self) -> Option<i128> {
+        Some(self.after? as i128 - self.before? as i128)
+    }
+}
+
+pub struct FreeSpaceMonitor {
+    mounts: Vec<MountSpace>,
+}
+
+impl FreeSpaceMonitor {
+    // Samples every filesystem that holds one of the paths, take it before anything is removed
+    pub fn sample<'a>(paths: impl IntoIterator<Item = &'a Path>) -> FreeSpaceMonitor {
+        let mut mounts: Vec<MountSpace> = vec![];
+        let mut seen = HashSet::new();
+        for path in paths {
+            let key = match get_mount_key(path) {
+                Some(key) => key,
+                None => continue,
+            };
+            if !seen.insert(key) {
+                continue;
+            }
+            let mount = match find_mount(path) {
+                Some(mount) => mount,
+                None => continue,
+            };
+            if mounts.iter().any(|space| space.mount == mount) {
+                continue;
+            }
+            mounts.push(MountSpace {
+                before: get_free_space(&mount).ok(),
+                mount,
+                after: None,
+            });
+        }
+        mounts.sort_by(|a, b| a.mount.cmp(&b.mount));
+        FreeSpaceMonitor { mounts }
+    }
+
+    // Samples the same filesystems again
+    pub fn finish(self) -> Vec<MountSpace> {
+        self.mounts.into_iter()
+            .map(|space| MountSpace {
+                after: get_free_space(&space.mount).ok(),
+                ..space
+            })
+            .collect()
+    }
+}
+
+#[cfg(unix)]
+fn get_mount_key(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    std::fs::symlink_metadata(path).ok().map(|metadata| metadata.dev())
+}
+
+#[cfg(not(unix))]
+fn get_mount_key(path: &Path) -> Option<PathBuf> {
+    find_mount(path)
+}
+
+// The topmost directory above the path that is still on the same device
+#[cfg(unix)]
+fn find_mount(path: &Path) -> Option<PathBuf> {
+    use std::os::unix::fs::MetadataExt;
+    let device = std::fs::symlink_metadata(path).ok()?.dev();
+    // the entry itself may be a symlink, only its parent is resolved
+    let path = match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => std::fs::canonicalize(parent).ok()?.join(name),
+        _ => std::fs::canonicalize(path).ok()?,
+    };
+    let mut mount = path.clone();
+    for ancestor in path.ancestors() {
+        match std::fs::symlink_metadata(ancestor) {
+            Ok(metadata) if metadata.dev() == device => mount = ancestor.to_path_buf(),
+            _ => break,
+        }
+    }
+    Some(mount)
+}
+
+// The drive the path is on, "C:\"
+#[cfg(not(unix))]
+fn find_mount(path: &Path) -> Option<PathBuf> {
+    use std::path::Component;
+    let mut mount = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => mount.push(component),
+            _ => break,
+        }
+    }
+    if mount.as_os_str().is_empty() {
+        return None;
+    }
+    Some(mount)
+}
+
+// Space available to the current user, not counting blocks reserved for root
+#[cfg(unix)]
+pub fn get_free_space(mount: &Path) -> io::Result<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    let path = CString::new(mount.as_os_str().as_bytes()).map_err(io::Error::other)?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(windows)]
+pub fn get_free_space(mount: &Path) -> io::Result<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetDiskFreeSpaceExW(directory: *const u16, free_to_caller: *mut u64, total: *mut u64, total_free: *mut u64) -> i32;
+    }
+    let path: Vec<u16> = mount.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut free = 0u64;
+    if unsafe { GetDiskFreeSpaceExW(path.as_ptr(), &mut free, std::ptr::null_mut(), std::ptr::null_mut()) } == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(free)
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn get_free_space(_mount: &Path) -> io::Result<u64> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
@@ -0,0 +1,193 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use database::structures::CleanerData;
+use database::utils::{format_timestamp, get_winbooster_directory};
+use crate::quarantine::{system_time_to_secs, unix_now};
+
+const JOURNAL_EXTENSION: &str = "jsonl";
+
+// One line of a run journal. The first line describes the run, the last one is written when it ends,
+// a run without it was interrupted.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JournalRecord {
+    Run {
+        run_id: String,
+        started: u64,
+        categories: Vec<String>,
+        disabled_programs: Vec<String>,
+        quarantine_run: Option<String>,
+    },
+    Removed(JournalEntry),
+    Finished {
+        finished: u64,
+        files: u64,
+        folders: u64,
+        bytes: u64,
+        errors: usize,
+        cancelled: bool,
+    },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub path: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified: u64,
+    // CleanerData::path of the rule that removed it
+    pub rule: String,
+    pub program: String,
+    pub category: String,
+}
+
+pub struct JournalRun {
+    pub run_id: String,
+    pub started: u64,
+    pub categories: Vec<String>,
+    pub disabled_programs: Vec<String>,
+    pub quarantine_run: Option<String>,
+    pub entries: Vec<JournalEntry>,
+    // None for a run that did not finish
+    pub finished: Option<u64>,
+    pub cancelled: bool,
+}
+
+pub struct Journal {
+    run_id: String,
+    file: Mutex<File>,
+}
+
+impl Journal {
+    // A quarantine run shares its id with the journal, so one id answers both questions
+    pub fn create(categories: &[&str], disabled_programs: &[&str], quarantine_run: Option<&str>) -> io::Result<Journal> {
+        fs::create_dir_all(get_journal_directory())?;
+        let run_id = match quarantine_run {
+            Some(run_id) => run_id.to_string(),
+            None => {
+                let timestamp = format_timestamp(unix_now()).replace(['-', ':'], "").replace(' ', "-");
+                let mut run_id = timestamp.clone();
+                let mut suffix = 1;
+                while get_journal_file(&run_id).exists() {
+                    suffix += 1;
+                    run_id = format!("{}-{}", timestamp, suffix);
+                }
+                run_id
+            }
+        };
+        let file = OpenOptions::new().create(true).append(true).open(get_journal_file(&run_id))?;
+        let journal = Journal {
+            run_id: run_id.clone(),
+            file: Mutex::new(file),
+        };
+        journal.write(&JournalRecord::Run {
+            run_id,
+            started: unix_now(),
+            categories: categories.iter().map(|category| category.to_string()).collect(),
+            disabled_programs: disabled_programs.iter().map(|program| program.to_string()).collect(),
+            quarantine_run: quarantine_run.map(str::to_string),
+        })?;
+        Ok(journal)
+    }
+
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    pub fn record_removed(&self, path: &str, is_dir: bool, size: u64, modified: Option<SystemTime>, data: &CleanerData) {
+        // a lost journal line must not stop the cleaning
+        let _ = self.write(&JournalRecord::Removed(JournalEntry {
+            path: path.to_string(),
+            is_dir,
+            size,
+            modified: modified.map(system_time_to_secs).unwrap_or(0),
+            rule: data.path.clone(),
+            program: data.program.clone(),
+            category: data.category.clone(),
+        }));
+    }
+
+    pub fn finish(&self, files: u64, folders: u64, bytes: u64, errors: usize, cancelled: bool) -> io::Result<()> {
+        self.write(&JournalRecord::Finished {
+            finished: unix_now(),
+            files,
+            folders,
+            bytes,
+            errors,
+            cancelled,
+        })
+    }
+
+    fn write(&self, record: &JournalRecord) -> io::Result<()> {
+        let line = serde_json::to_string(record).map_err(io::Error::other)?;
+        let mut file = self.file.lock().unwrap();
+        writeln!(file, "{}", line)
+    }
+}
+
+pub fn get_journal_directory() -> PathBuf {
+    get_winbooster_directory().join("journal")
+}
+
+fn get_journal_file(run_id: &str) -> PathBuf {
+    get_journal_directory().join(format!("{}.{}", run_id, JOURNAL_EXTENSION))
+}
+
+// Every journaled run, oldest first
+pub fn list_runs() -> Vec<JournalRun> {
+    let mut runs = vec![];
+    let entries = match fs::read_dir(get_journal_directory()) {
+        Ok(entries) => entries,
+        Err(_) => return runs,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some(JOURNAL_EXTENSION) {
+            continue;
+        }
+        let run_id = match path.file_stem() {
+            Some(run_id) => run_id.to_string_lossy().to_string(),
+            None => continue,
+        };
+        if let Some(run) = read_run(&run_id) {
+            runs.push(run);
+        }
+    }
+    runs.sort_by(|a, b| a.started.cmp(&b.started).then_with(|| a.run_id.cmp(&b.run_id)));
+    runs
+}
+
+pub fn read_run(run_id: &str) -> Option<JournalRun> {
+    let file = File::open(get_journal_file(run_id)).ok()?;
+    let mut run = JournalRun {
+        run_id: run_id.to_string(),
+        started: 0,
+        categories: vec![],
+        disabled_programs: vec![],
+        quarantine_run: None,
+        entries: vec![],
+        finished: None,
+        cancelled: false,
+    };
+    // a line cut off by a crash is skipped, the rest of the journal is still useful
+    for record in BufReader::new(file).lines().map_while(Result::ok).filter_map(|line| serde_json::from_str(&line).ok()) {
+        match record {
+            JournalRecord::Run { started, categories, disabled_programs, quarantine_run, .. } => {
+                run.started = started;
+                run.categories = categories;
+                run.disabled_programs = disabled_programs;
+                run.quarantine_run = quarantine_run;
+            }
+            JournalRecord::Removed(entry) => run.entries.push(entry),
+            JournalRecord::Finished { finished, cancelled, .. } => {
+                run.finished = Some(finished);
+                run.cancelled = cancelled;
+            }
+        }
+    }
+    Some(run)
+}
@@ -1,149 +1,751 @@
-use std::fs;
-use std::path::Path;
-use glob::{glob, Paths, PatternError};
-use database::structures::{CleanerData, CleanerResult, Cleared};
+use std::io;
+use std::collections::{HashMap, HashSet};
+use std::cell::{Cell, RefCell};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use glob::{Pattern, PatternError};
+use database::structures::{CleanerAction, CleanerData, CleanerError, CleanerErrorKind, CleanerLock, CleanerOperation, CleanerResult, CleanerRetention, CleanerSkip, CleanerSkipReason, PreviewEntry};
+use crate::accounting::{collect_usage, FileUsage, SpaceAccounting};
+use crate::cancellation::CancellationToken;
+use crate::exclusions::{compile_patterns, global_match_options, is_excluded};
+use crate::filesystem::{FileSystem, GlobFailure, GlobPaths, RealFileSystem};
+use crate::journal::Journal;
+use crate::matching::{find_entry, match_options, rule_pattern};
+use crate::plan::{CleanerPlan, PlannedPath};
+use crate::observer::{CleanerEvent, CleanerEventKind, CleanerObserver};
+use crate::processes::{describe_processes, OpenFiles, RunningProgramPolicy};
+use crate::prune::{glob_root, prune_empty_parents};
+use crate::protection::{is_protected, is_well_formed_entry, is_well_formed_pattern};
+use crate::quarantine::Quarantine;
+use crate::throttle::{LoweredPriority, Throttle};
 
-pub fn clear_data(data: &CleanerData) -> CleanerResult {
-    let mut cleaner_result: CleanerResult = CleanerResult {
-        files: 0,
-        folders: 0,
-        bytes: 0,
-        working: false,
-        program: String::new(),
-        path: String::new()
+pub mod accounting;
+pub mod cancellation;
+pub mod containment;
+pub mod exclusions;
+pub mod filesystem;
+pub mod free_space;
+pub mod journal;
+pub mod matching;
+pub mod memory_filesystem;
+pub mod observer;
+pub mod plan;
+pub mod pool;
+pub mod processes;
+pub mod protection;
+pub mod prune;
+pub mod quarantine;
+pub mod shred;
+pub mod throttle;
+
+#[derive(Clone, Default)]
+pub struct CleanerOptions {
+    pub quarantine: Option<Arc<Quarantine>>,
+    // overrides CleanerData::min_age_days for every rule
+    pub min_age_days: Option<u64>,
+    // global user exclusions, checked together with CleanerData::exclude but always with the platform's case rules
+    pub exclusions: Vec<Pattern>,
+    // allow removing through symlinks, junctions and mount points
+    pub follow_symlinks: bool,
+    // user additions to the protected-path deny-list
+    pub protected_paths: Vec<PathBuf>,
+    // number of rules cleaned at the same time, 0 picks pool::default_concurrency()
+    pub concurrency: usize,
+    // checked between file operations, a cancelled run returns what it removed so far
+    pub cancellation: CancellationToken,
+    // notified about every scanned, removed, skipped and failed path
+    pub observer: Option<Arc<dyn CleanerObserver>>,
+    // categories whose files are overwritten before removal, with the number of passes
+    pub secure_overwrite: HashMap<String, u32>,
+    // what happens to rules of programs that are running
+    pub running_programs: RunningProgramPolicy,
+    // remove directories left empty by a rule, never above the literal part of its pattern.
+    // Ignored with quarantine, a run that can be restored must not delete anything
+    pub prune_empty_directories: bool,
+    // None is the real disk. Quarantine only moves real files, rules refuse to run with both set
+    pub filesystem: Option<Arc<dyn FileSystem>>,
+    // every removed path is recorded here
+    pub journal: Option<Arc<Journal>>,
+    // hard-link-aware apparent, allocated and reclaimed totals of the run
+    pub accounting: Option<Arc<SpaceAccounting>>,
+    // background mode, paces deletions and scanned bytes and lowers the priority of the threads running rules
+    pub throttle: Option<Arc<Throttle>>,
+    // files other processes hold open, taken once per run with OpenFiles::snapshot. None skips the check
+    pub open_files: Option<Arc<OpenFiles>>,
+}
+
+impl CleanerOptions {
+    pub fn filesystem(&self) -> &dyn FileSystem {
+        match &self.filesystem {
+            Some(filesystem) => filesystem.as_ref(),
+            None => &RealFileSystem,
+        }
+    }
+}
+
+// What is known about a path right before it is removed
+struct Measurement {
+    size: u64,
+    // files and symlinks at or below the path
+    entries: u64,
+    modified: Option<SystemTime>,
+    usages: Vec<FileUsage>,
+}
+
+struct Rule<'a> {
+    data: &'a CleanerData,
+    options: &'a CleanerOptions,
+    // CleanerData::exclude, matched like the rule's path
+    exclusions: Vec<Pattern>,
+    aborted: Cell<bool>,
+    // paths already in the plan, a rule over a whole AppData can plan tens of thousands
+    planned: RefCell<HashSet<String>>,
+}
+
+impl<'a> Rule<'a> {
+    fn new(data: &'a CleanerData, options: &'a CleanerOptions) -> Result<Rule<'a>, CleanerError> {
+        if !is_well_formed_pattern(&data.path) {
+            return Err(pattern_error(&data.path));
+        }
+        if let Some(entry) = data.named_entries().find(|entry| !is_well_formed_entry(entry)) {
+            return Err(pattern_error(&(data.path.to_owned() + "\\" + entry)));
+        }
+        CleanerAction::validate(&data.actions).map_err(|reason| CleanerError {
+            path: format!("{} ({})", data.path, reason),
+            operation: CleanerOperation::Glob,
+            kind: CleanerErrorKind::InvalidRule,
+        })?;
+        if options.quarantine.is_some() && options.filesystem.is_some() {
+            return Err(CleanerError {
+                path: data.path.clone(),
+                operation: CleanerOperation::Quarantine,
+                kind: CleanerErrorKind::Unsupported,
+            });
+        }
+        let exclusions = compile_patterns(&data.exclude).map_err(|pattern| pattern_error(&pattern))?;
+        Ok(Rule { data, options, exclusions, aborted: Cell::new(false), planned: RefCell::new(HashSet::new()) })
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        is_old_enough(path, self.data, self.options) && !self.excludes(path)
+    }
+
+    fn excludes(&self, path: &Path) -> bool {
+        let filesystem = self.options.filesystem();
+        is_excluded(filesystem, path, &self.exclusions, match_options(self.data))
+            || is_excluded(filesystem, path, &self.options.exclusions, global_match_options())
+    }
+
+    fn plan_path(&self, plan: &mut CleanerPlan, path: &str, is_dir: bool, files: u64, folders: u64) {
+        if self.planned.borrow().contains(path) {
+            return;
+        }
+        if !self.allows(Path::new(path)) {
+            if self.options.observer.is_some() {
+                self.notify(CleanerEventKind::Skipped, path, get_size(self.options.filesystem(), Path::new(path)));
+            }
+            return;
+        }
+        self.planned.borrow_mut().insert(path.to_string());
+        plan.paths.push(PlannedPath {
+            path: path.to_string(),
+            is_dir,
+            files,
+            folders,
+        });
+    }
+
+    // A rule that reaches a protected path is stopped completely, it is most likely broken
+    fn check_protected(&self, cleaner_result: &mut CleanerResult, path: &str, operation: CleanerOperation) -> bool {
+        if self.aborted.get() {
+            return false;
+        }
+        if !is_protected(Path::new(path), &self.options.protected_paths) {
+            return true;
+        }
+        self.aborted.set(true);
+        cleaner_result.errors.push(CleanerError {
+            path: path.to_string(),
+            operation,
+            kind: CleanerErrorKind::Protected,
+        });
+        false
+    }
+
+    fn notify(&self, kind: CleanerEventKind, path: &str, bytes: u64) {
+        if let Some(observer) = &self.options.observer {
+            observer.notify(&CleanerEvent { kind, path, bytes });
+        }
+    }
+
+    fn check_containment(&self, path: &Path) -> Option<CleanerSkip> {
+        if self.options.follow_symlinks {
+            return None;
+        }
+        let filesystem = self.options.filesystem();
+        containment::check_path(filesystem, path).or_else(|| containment::check_directory(filesystem, path))
+    }
+
+    // The modification time is only needed for the journal, the per-file usage only for accounting
+    fn measure(&self, path: &str) -> Measurement {
+        let filesystem = self.options.filesystem();
+        let modified = match self.options.journal {
+            Some(_) => filesystem.symlink_metadata(Path::new(path)).ok().and_then(|metadata| metadata.modified),
+            None => None,
+        };
+        let measurement = if self.options.accounting.is_none() {
+            let (size, entries) = get_size_and_entries(filesystem, Path::new(path));
+            Measurement { size, entries, modified, usages: vec![] }
+        }
+        else {
+            let mut usages = vec![];
+            collect_usage(filesystem, Path::new(path), &mut usages);
+            Measurement { size: usages.iter().map(|usage| usage.apparent).sum(), entries: usages.len() as u64, modified, usages }
+        };
+        self.throttle_scanned(measurement.size);
+        measurement
+    }
+
+    // Held while the rule runs, the thread gets its priority back when the rule is done
+    fn lower_priority(&self) -> Option<LoweredPriority> {
+        self.options.throttle.as_ref().and_then(|throttle| throttle.lower_priority())
+    }
+
+    fn throttle_scanned(&self, bytes: u64) {
+        if let Some(throttle) = &self.options.throttle {
+            throttle.wait_scanned(bytes, &self.options.cancellation);
+        }
+    }
+
+    // An emptied directory counts as one deletion
+    fn throttle_deletions(&self, entries: u64) {
+        if let Some(throttle) = &self.options.throttle {
+            throttle.wait_deletions(entries.max(1), &self.options.cancellation);
+        }
+    }
+
+    fn record_removed(&self, path: &str, is_dir: bool, size: u64, modified: Option<SystemTime>) {
+        if let Some(journal) = &self.options.journal {
+            journal.record_removed(path, is_dir, size, modified, self.data);
+        }
+    }
+
+    fn account<'u>(&self, usages: impl IntoIterator<Item = &'u FileUsage>, freed: bool) {
+        if let Some(accounting) = &self.options.accounting {
+            for usage in usages {
+                accounting.record(usage, freed);
+            }
+        }
+    }
+
+    // unlinking an open file works on unix, the holder would keep writing to a file nobody sees
+    fn check_locked(&self, path: &str) -> Option<CleanerLock> {
+        let holders = self.options.open_files.as_ref()?.holders(self.options.filesystem(), Path::new(path));
+        if holders.is_empty() {
+            return None;
+        }
+        Some(CleanerLock {
+            path: path.to_string(),
+            holders: describe_processes(holders),
+        })
+    }
+
+    // A sharing violation is reported as a lock, the path can be retried once the holder is gone
+    fn add_remove_error(&self, cleaner_result: &mut CleanerResult, path: &str, operation: CleanerOperation, error: &io::Error) {
+        if CleanerErrorKind::from_io_error(error) != CleanerErrorKind::InUse {
+            add_io_error(&mut cleaner_result.errors, path, operation, error);
+            return;
+        }
+        let lock = self.check_locked(path).unwrap_or_else(|| CleanerLock {
+            path: path.to_string(),
+            holders: "unknown process".to_string(),
+        });
+        cleaner_result.locked.push(lock);
+    }
+}
+
+pub fn clear_data(data: &CleanerData, options: &CleanerOptions) -> CleanerResult {
+    clear_plan(&plan_data(data, options), options)
+}
+
+// Resolves the rule into the paths it would remove, nothing is touched yet
+pub fn plan_data(data: &CleanerData, options: &CleanerOptions) -> CleanerPlan {
+    let mut plan = CleanerPlan {
+        data: data.clone(),
+        paths: vec![],
+        errors: vec![],
+        skipped: vec![],
     };
 
-    let results: Result<Paths, PatternError> = glob(&*data.path);
-    cleaner_result.program = (&*data.program).parse().unwrap();
-    cleaner_result.path = (&*data.path).parse().unwrap();
+    let rule = match Rule::new(data, options) {
+        Ok(rule) => rule,
+        Err(error) => {
+            plan.errors.push(error);
+            return plan;
+        }
+    };
+    let results: Result<GlobPaths, PatternError> = options.filesystem().glob(&rule_pattern(data), match_options(data));
     match results {
         Ok(results) => {
             for result in results {
+                if options.cancellation.is_cancelled() {
+                    break;
+                }
                 match result {
                     Ok(result) => {
-                        let is_dir: bool = result.is_dir();
-                        let is_file: bool = result.is_file();
-                        let path: &str = result.as_path().to_str().unwrap();
-                        let mut lenght = 0;
-                        match result.metadata() {
-                            Ok(res) => { lenght += res.len(); }
-                            Err(_) => {}
-                        }
-                        //println!("Found: {}", path);
-                        for file in &data.files_to_remove {
-                            let file_path = path.to_owned() + "\\" + &*file;
-                            match fs::remove_file(file_path) {
-                                Ok(_) => {
-                                    cleaner_result.files += 1;
-                                    cleaner_result.bytes += lenght;
-                                    cleaner_result.working = true;
-                                }
-                                Err(_) => {}
-                            }
-                        }
-                        for directory in &data.directories_to_remove {
-                            let file_path = path.to_owned() + "\\" + &*directory;
-                            let metadata = fs::metadata(file_path.clone());
-                            match metadata {
-                                Ok(res) => { lenght += res.len(); }
-                                Err(_) => {}
-                            }
-                            match fs::remove_dir_all(file_path) {
-                                Ok(_) => {
-                                    cleaner_result.folders += 1;
-                                    cleaner_result.working = true;
-                                }
-                                Err(_) => {}
+                        let path: &str = match result.as_path().to_str() {
+                            Some(path) => path,
+                            None => {
+                                plan.errors.push(CleanerError {
+                                    path: result.to_string_lossy().to_string(),
+                                    operation: CleanerOperation::Glob,
+                                    kind: CleanerErrorKind::InvalidPath,
+                                });
+                                continue;
                             }
+                        };
+                        for action in &data.actions {
+                            plan_action(&mut plan, &rule, action, path);
                         }
+                    }
+                    Err(error) => {
+                        add_glob_error(&mut plan.errors, &error);
+                    }
+                }
+            }
+        }
+        Err(_) => {
+            add_pattern_error(&mut plan.errors, &data.path);
+        }
+    }
+    if let Some(retention) = data.retention {
+        apply_retention(&mut plan, &rule, retention);
+    }
 
-                        for dir in &data.directories_to_remove {
-                            let dir_path = path.to_owned() + "\\" + &*dir;
-                            let metadata = fs::metadata(dir_path.clone());
-                            match metadata {
-                                Ok(res) => { lenght += res.len(); }
-                                Err(_) => {}
-                            }
-                            match fs::remove_dir_all(dir_path) {
-                                Ok(_) => {
-                                    cleaner_result.folders += 1;
-                                    cleaner_result.bytes += lenght;
-                                    cleaner_result.working = true;
-                                }
-                                Err(_) => {}
-                            }
-                        }
+    // A rule that reaches a protected path is dropped completely, it is most likely broken
+    if let Some(protected) = plan.paths.iter().find(|planned| is_protected(Path::new(&planned.path), &options.protected_paths)) {
+        plan.errors.push(CleanerError {
+            path: protected.path.clone(),
+            operation: if protected.is_dir { CleanerOperation::RemoveDirectory } else { CleanerOperation::RemoveFile },
+            kind: CleanerErrorKind::Protected,
+        });
+        plan.paths.clear();
+    }
+    plan
+}
 
-                        //println!("Found: {}", path);
-                        if data.remove_files && is_file {
-                            let path = Path::new(path);
-                            match fs::remove_file(path) {
-                                Ok(_) => {
-                                    cleaner_result.files += 1;
-                                    cleaner_result.bytes += lenght;
-                                    cleaner_result.working = true;
-                                }
-                                Err(_) => {}
-                            }
-                        }
-                        if data.remove_directories && is_dir {
-                            match fs::remove_dir_all(path) {
-                                Ok(_) => {
-                                    cleaner_result.folders += 1;
-                                    cleaner_result.bytes += lenght;
-                                    cleaner_result.working = true;
-                                }
-                                Err(_) => {}
-                            }
-                        }
-                        if data.remove_all_in_dir {
-                            let results: Result<Paths, PatternError> = glob(&*(path.to_owned() + "\\*"));
-                            let mut files = 0;
-                            let mut dirs = 0;
-                            match results {
-                                Ok(results) => {
-                                    for result in results {
-                                        match result {
-                                            Ok(result) => {
-                                                if result.is_file() {
-                                                    files += 1;
-                                                }
-                                                if result.is_dir() {
-                                                    dirs += 1;
-                                                }
-                                            }
-                                            Err(_) => {}
-                                        }
-                                    }
-                                    match fs::remove_dir_all(path) {
-                                        Ok(_) => {
-                                            cleaner_result.files += files;
-                                            cleaner_result.folders += dirs;
-                                            cleaner_result.bytes += lenght;
-                                            cleaner_result.working = true;
-                                        }
-                                        Err(_) => {}
-                                    }
-                                }
-                                Err(_) => {}
-                            }
+fn plan_action(plan: &mut CleanerPlan, rule: &Rule, action: &CleanerAction, path: &str) {
+    let filesystem = rule.options.filesystem();
+    let metadata = filesystem.metadata(Path::new(path)).ok();
+    let is_dir: bool = metadata.map(|metadata| metadata.is_dir()).unwrap_or(false);
+    let is_file: bool = metadata.map(|metadata| metadata.is_file()).unwrap_or(false);
+    match action {
+        CleanerAction::RemoveNamedFiles(files) => {
+            for file in files {
+                let file_path = match find_entry(filesystem, path, file, rule.data) {
+                    Some(file_path) => file_path,
+                    None => continue,
+                };
+                match filesystem.symlink_metadata(Path::new(&file_path)) {
+                    Ok(metadata) if !metadata.is_dir() => rule.plan_path(plan, &file_path, false, 1, 0),
+                    _ => {}
+                }
+            }
+        }
+        CleanerAction::RemoveNamedDirectories(directories) => {
+            for directory in directories {
+                let dir_path = match find_entry(filesystem, path, directory, rule.data) {
+                    Some(dir_path) => dir_path,
+                    None => continue,
+                };
+                if filesystem.metadata(Path::new(&dir_path)).map(|metadata| metadata.is_dir()).unwrap_or(false) {
+                    rule.plan_path(plan, &dir_path, true, 0, 1);
+                }
+            }
+        }
+        CleanerAction::RemoveFile => {
+            if is_file {
+                rule.plan_path(plan, path, false, 1, 0);
+            }
+        }
+        CleanerAction::RemoveDirectory => {
+            if is_dir {
+                rule.plan_path(plan, path, true, 0, 1);
+            }
+        }
+        CleanerAction::RemoveDirectoryContents => {
+            if !is_dir {
+                return;
+            }
+            match filesystem.read_dir(Path::new(path)) {
+                Ok(entries) => {
+                    for entry in entries {
+                        let child = entry.to_string_lossy().to_string();
+                        let child_is_dir = filesystem.symlink_metadata(&entry).map(|metadata| metadata.is_dir()).unwrap_or(false);
+                        if child_is_dir {
+                            rule.plan_path(plan, &child, true, 0, 1);
                         }
-                        if data.remove_directory_after_clean {
-                            match fs::remove_dir_all(path) {
-                                Ok(_) => {
-                                    cleaner_result.folders += 1;
-                                    cleaner_result.working = true;
-                                }
-                                Err(_) => {}
-                            }
+                        else {
+                            rule.plan_path(plan, &child, false, 1, 0);
                         }
                     }
-                    Err(_) => {}
+                }
+                Err(error) => {
+                    add_io_error(&mut plan.errors, path, CleanerOperation::Glob, &error);
                 }
             }
         }
-        Err(_) => {}
+    }
+}
+
+pub fn clear_plan(plan: &CleanerPlan, options: &CleanerOptions) -> CleanerResult {
+    let mut cleaner_result: CleanerResult = CleanerResult {
+        files: 0,
+        folders: 0,
+        bytes: 0,
+        working: false,
+        program: String::new(),
+        path: String::new(),
+        errors: plan.errors.clone(),
+        skipped: plan.skipped.clone(),
+        overwritten_bytes: 0,
+        locked: vec![],
+    };
+    cleaner_result.program = plan.data.program.clone();
+    cleaner_result.path = plan.data.path.clone();
+
+    let rule = match Rule::new(&plan.data, options) {
+        Ok(rule) => rule,
+        Err(_) => return cleaner_result,
+    };
+    let _priority = rule.lower_priority();
+    let mut removed_paths = vec![];
+    for planned in &plan.paths {
+        if rule.aborted.get() || options.cancellation.is_cancelled() {
+            break;
+        }
+        let removed = if planned.is_dir {
+            remove_directory(&mut cleaner_result, &rule, &planned.path)
+        }
+        else {
+            remove_file(&mut cleaner_result, &rule, &planned.path)
+        };
+        if removed {
+            cleaner_result.files += planned.files;
+            cleaner_result.folders += planned.folders;
+            removed_paths.push(&planned.path);
+        }
+    }
+    if options.prune_empty_directories && options.quarantine.is_none() && !rule.aborted.get() && !options.cancellation.is_cancelled() {
+        prune_directories(&mut cleaner_result, &rule, &removed_paths);
     }
 
     cleaner_result
-}
\ No newline at end of file
+}
+
+fn prune_directories(cleaner_result: &mut CleanerResult, rule: &Rule, removed_paths: &[&String]) {
+    let root = glob_root(&rule.data.path);
+    let keep = |directory: &Path| {
+        is_protected(directory, &rule.options.protected_paths) || rule.excludes(directory)
+    };
+    for path in removed_paths {
+        for directory in prune_empty_parents(rule.options.filesystem(), Path::new(path), &root, keep) {
+            cleaner_result.folders += 1;
+            cleaner_result.working = true;
+            rule.notify(CleanerEventKind::Removed, &directory.to_string_lossy(), 0);
+            rule.record_removed(&directory.to_string_lossy(), true, 0, None);
+        }
+    }
+}
+
+fn remove_file(cleaner_result: &mut CleanerResult, rule: &Rule, path: &str) -> bool {
+    if rule.options.cancellation.is_cancelled() || !rule.check_protected(cleaner_result, path, CleanerOperation::RemoveFile) {
+        return false;
+    }
+    let measurement = rule.measure(path);
+    let size = measurement.size;
+    rule.notify(CleanerEventKind::Scanned, path, size);
+    if let Some(skip) = rule.check_containment(Path::new(path)) {
+        cleaner_result.skipped.push(skip);
+        rule.notify(CleanerEventKind::Skipped, path, size);
+        return false;
+    }
+    if let Some(lock) = rule.check_locked(path) {
+        cleaner_result.locked.push(lock);
+        rule.notify(CleanerEventKind::Skipped, path, size);
+        return false;
+    }
+    rule.throttle_deletions(measurement.entries);
+    if rule.options.cancellation.is_cancelled() {
+        return false;
+    }
+    if let Some(quarantine) = &rule.options.quarantine {
+        return quarantine_path(cleaner_result, rule, quarantine, path, false, &measurement);
+    }
+    if !overwrite(cleaner_result, rule, path, size) {
+        return false;
+    }
+    match rule.options.filesystem().remove_file(Path::new(path)) {
+        Ok(_) => {
+            cleaner_result.bytes += size;
+            cleaner_result.working = true;
+            rule.notify(CleanerEventKind::Removed, path, size);
+            rule.record_removed(path, false, size, measurement.modified);
+            rule.account(&measurement.usages, true);
+            true
+        }
+        Err(error) => {
+            rule.add_remove_error(cleaner_result, path, CleanerOperation::RemoveFile, &error);
+            rule.notify(CleanerEventKind::Failed, path, size);
+            false
+        }
+    }
+}
+
+fn remove_directory(cleaner_result: &mut CleanerResult, rule: &Rule, path: &str) -> bool {
+    if rule.options.cancellation.is_cancelled() || !rule.check_protected(cleaner_result, path, CleanerOperation::RemoveDirectory) {
+        return false;
+    }
+    let measurement = rule.measure(path);
+    let size = measurement.size;
+    rule.notify(CleanerEventKind::Scanned, path, size);
+    if let Some(skip) = rule.check_containment(Path::new(path)) {
+        cleaner_result.skipped.push(skip);
+        rule.notify(CleanerEventKind::Skipped, path, size);
+        return false;
+    }
+    if let Some(lock) = rule.check_locked(path) {
+        cleaner_result.locked.push(lock);
+        rule.notify(CleanerEventKind::Skipped, path, size);
+        return false;
+    }
+    rule.throttle_deletions(measurement.entries);
+    if rule.options.cancellation.is_cancelled() {
+        return false;
+    }
+    if let Some(quarantine) = &rule.options.quarantine {
+        return quarantine_path(cleaner_result, rule, quarantine, path, true, &measurement);
+    }
+    if !overwrite(cleaner_result, rule, path, size) {
+        return false;
+    }
+    match rule.options.filesystem().remove_dir_all(Path::new(path)) {
+        Ok(_) => {
+            cleaner_result.bytes += size;
+            cleaner_result.working = true;
+            rule.notify(CleanerEventKind::Removed, path, size);
+            rule.record_removed(path, true, size, measurement.modified);
+            rule.account(&measurement.usages, true);
+            true
+        }
+        Err(error) => {
+            // remove_dir_all stops at the first failure, count what it managed to delete
+            let filesystem = rule.options.filesystem();
+            let removed = size.saturating_sub(get_size(filesystem, Path::new(path)));
+            if removed > 0 {
+                cleaner_result.bytes += removed;
+                cleaner_result.working = true;
+                rule.notify(CleanerEventKind::Removed, path, removed);
+                rule.record_removed(path, true, removed, measurement.modified);
+                rule.account(measurement.usages.iter().filter(|usage| filesystem.symlink_metadata(&usage.path).is_err()), true);
+            }
+            rule.add_remove_error(cleaner_result, path, CleanerOperation::RemoveDirectory, &error);
+            rule.notify(CleanerEventKind::Failed, path, size - removed);
+            false
+        }
+    }
+}
+
+fn quarantine_path(cleaner_result: &mut CleanerResult, rule: &Rule, quarantine: &Quarantine, path: &str, is_dir: bool, measurement: &Measurement) -> bool {
+    let size = measurement.size;
+    match quarantine.store(Path::new(path), size, rule.data) {
+        Ok(_) => {
+            cleaner_result.bytes += size;
+            cleaner_result.working = true;
+            rule.notify(CleanerEventKind::Removed, path, size);
+            rule.record_removed(path, is_dir, size, measurement.modified);
+            rule.account(&measurement.usages, false);
+            true
+        }
+        Err(error) => {
+            add_io_error(&mut cleaner_result.errors, path, CleanerOperation::Quarantine, &error);
+            rule.notify(CleanerEventKind::Failed, path, size);
+            false
+        }
+    }
+}
+
+// In secure mode the content is destroyed first, a path that could not be overwritten is left in place
+fn overwrite(cleaner_result: &mut CleanerResult, rule: &Rule, path: &str, size: u64) -> bool {
+    let passes = match rule.options.secure_overwrite.get(&rule.data.category) {
+        Some(passes) => *passes,
+        None => return true,
+    };
+    match rule.options.filesystem().overwrite(Path::new(path), passes) {
+        Ok(overwritten) => {
+            cleaner_result.overwritten_bytes += overwritten.written;
+            // removing one of several links frees nothing, the content stays behind the other ones
+            cleaner_result.skipped.extend(overwritten.hard_linked.iter().map(|linked| CleanerSkip {
+                path: linked.to_string_lossy().to_string(),
+                reason: CleanerSkipReason::HardLinked,
+            }));
+            true
+        }
+        Err(error) => {
+            add_io_error(&mut cleaner_result.errors, path, CleanerOperation::Overwrite, &error);
+            rule.notify(CleanerEventKind::Failed, path, size);
+            false
+        }
+    }
+}
+
+fn add_io_error(errors: &mut Vec<CleanerError>, path: &str, operation: CleanerOperation, error: &io::Error) {
+    errors.push(CleanerError {
+        path: path.to_string(),
+        operation,
+        kind: CleanerErrorKind::from_io_error(error),
+    });
+}
+
+fn add_glob_error(errors: &mut Vec<CleanerError>, failure: &GlobFailure) {
+    errors.push(CleanerError {
+        path: failure.path.to_string_lossy().to_string(),
+        operation: CleanerOperation::Glob,
+        kind: CleanerErrorKind::from_io_error(&failure.error),
+    });
+}
+
+fn add_pattern_error(errors: &mut Vec<CleanerError>, pattern: &str) {
+    errors.push(pattern_error(pattern));
+}
+
+fn pattern_error(pattern: &str) -> CleanerError {
+    CleanerError {
+        path: pattern.to_string(),
+        operation: CleanerOperation::Glob,
+        kind: CleanerErrorKind::InvalidPattern,
+    }
+}
+
+pub fn preview_data(data: &CleanerData, options: &CleanerOptions) -> Vec<PreviewEntry> {
+    preview_plan(&plan_data(data, options), options)
+}
+
+pub fn preview_plan(plan: &CleanerPlan, options: &CleanerOptions) -> Vec<PreviewEntry> {
+    let mut entries: Vec<PreviewEntry> = vec![];
+    let rule = match Rule::new(&plan.data, options) {
+        Ok(rule) => rule,
+        Err(_) => return entries,
+    };
+    let _priority = rule.lower_priority();
+
+    for planned in &plan.paths {
+        if options.cancellation.is_cancelled() {
+            break;
+        }
+        let path = Path::new(&planned.path);
+        if rule.check_containment(path).is_some() {
+            continue;
+        }
+        let bytes = get_size(options.filesystem(), path);
+        rule.throttle_scanned(bytes);
+        rule.notify(CleanerEventKind::Scanned, &planned.path, bytes);
+        entries.push(PreviewEntry {
+            path: planned.path.clone(),
+            program: plan.data.program.clone(),
+            category: plan.data.category.clone(),
+            is_dir: planned.is_dir,
+            bytes,
+        });
+    }
+
+    entries
+}
+
+// Takes the newest planned paths out of the plan, they are kept on disk
+fn apply_retention(plan: &mut CleanerPlan, rule: &Rule, retention: CleanerRetention) {
+    let filesystem = rule.options.filesystem();
+    let mut paths: Vec<(PlannedPath, Option<SystemTime>, u64)> = plan.paths.drain(..)
+        .map(|planned| {
+            let modified = get_last_modified(filesystem, Path::new(&planned.path));
+            let size = get_size(filesystem, Path::new(&planned.path));
+            (planned, modified, size)
+        })
+        .collect();
+    // newest first, paths without a modification time count as the oldest
+    paths.sort_by_key(|path| std::cmp::Reverse(path.1));
+
+    let mut kept_bytes: u64 = 0;
+    let mut keeping = true;
+    for (index, (planned, _, size)) in paths.into_iter().enumerate() {
+        if keeping {
+            keeping = match retention {
+                CleanerRetention::KeepNewest(count) => index < count,
+                CleanerRetention::KeepNewestBytes(bytes) => kept_bytes + size <= bytes,
+            };
+        }
+        if keeping {
+            kept_bytes += size;
+            rule.notify(CleanerEventKind::Skipped, &planned.path, size);
+        }
+        else {
+            plan.paths.push(planned);
+        }
+    }
+}
+
+fn is_old_enough(path: &Path, data: &CleanerData, options: &CleanerOptions) -> bool {
+    let min_age_days = match options.min_age_days.or(data.min_age_days) {
+        Some(min_age_days) if min_age_days > 0 => min_age_days,
+        _ => return true,
+    };
+    let threshold = match SystemTime::now().checked_sub(Duration::from_secs(min_age_days * 24 * 60 * 60)) {
+        Some(threshold) => threshold,
+        None => return true,
+    };
+    match get_last_modified(options.filesystem(), path) {
+        Some(modified) => modified <= threshold,
+        None => false,
+    }
+}
+
+// newest modification time of the path, for directories the newest of everything inside
+pub fn get_last_modified(filesystem: &dyn FileSystem, path: &Path) -> Option<SystemTime> {
+    let metadata = filesystem.symlink_metadata(path).ok()?;
+    let mut last_modified = metadata.modified?;
+    if metadata.is_dir() && let Ok(entries) = filesystem.read_dir(path) {
+        for entry in entries {
+            if let Some(modified) = get_last_modified(filesystem, &entry) {
+                last_modified = last_modified.max(modified);
+            }
+        }
+    }
+    Some(last_modified)
+}
+
+pub fn get_size(filesystem: &dyn FileSystem, path: &Path) -> u64 {
+    get_size_and_entries(filesystem, path).0
+}
+
+// Size and number of files and symlinks, in one walk
+fn get_size_and_entries(filesystem: &dyn FileSystem, path: &Path) -> (u64, u64) {
+    let metadata = match filesystem.symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return (0, 0),
+    };
+    if !metadata.is_dir() {
+        return (metadata.len, 1);
+    }
+
+    let mut size = 0;
+    let mut count = 0;
+    if let Ok(entries) = filesystem.read_dir(path) {
+        for entry in entries {
+            let (entry_size, entry_count) = get_size_and_entries(filesystem, &entry);
+            size += entry_size;
+            count += entry_count;
+        }
+    }
+    (size, count)
+}
@@ -0,0 +1,61 @@
+use std::path::Path;
+use glob::{MatchOptions, Pattern};
+use database::structures::CleanerData;
+use crate::filesystem::FileSystem;
+
+pub fn match_options(data: &CleanerData) -> MatchOptions {
+    MatchOptions {
+        case_sensitive: !data.case_insensitive,
+        ..MatchOptions::new()
+    }
+}
+
+// The pattern passed to glob for a rule. glob compares components without wildcards with the
+// filesystem directly, so on case-sensitive systems every literal component gets a character class
+// ("Code Cache" becomes "[C]ode Cache") to go through MatchOptions as well.
+pub fn rule_pattern(data: &CleanerData) -> String {
+    if !data.case_insensitive || cfg!(windows) {
+        return data.path.clone();
+    }
+    case_insensitive_pattern(&data.path)
+}
+
+fn case_insensitive_pattern(pattern: &str) -> String {
+    let mut result = String::with_capacity(pattern.len() + 8);
+    let mut component = String::new();
+    for character in pattern.chars() {
+        if character == '/' || character == '\\' {
+            result.push_str(&case_insensitive_component(&component));
+            result.push(character);
+            component.clear();
+        }
+        else {
+            component.push(character);
+        }
+    }
+    result.push_str(&case_insensitive_component(&component));
+    result
+}
+
+fn case_insensitive_component(component: &str) -> String {
+    if component.contains(['*', '?', '[', ']']) {
+        return component.to_string();
+    }
+    match component.char_indices().find(|(_, character)| character.is_ascii_alphabetic()) {
+        Some((index, character)) => format!("{}[{}]{}", &component[..index], character, &component[index + 1..]),
+        None => component.to_string(),
+    }
+}
+
+// Resolves a named entry of a rule inside a matched directory, ignoring case when the rule asks for it
+pub fn find_entry(filesystem: &dyn FileSystem, directory: &str, entry: &str, data: &CleanerData) -> Option<String> {
+    let joined = Path::new(directory).join(entry);
+    if filesystem.symlink_metadata(&joined).is_ok() || !data.case_insensitive || cfg!(windows) {
+        return Some(joined.to_string_lossy().to_string());
+    }
+    let pattern = Pattern::escape(directory) + "/" + &case_insensitive_pattern(&Pattern::escape(entry));
+    match filesystem.glob(&pattern, match_options(data)) {
+        Ok(mut paths) => paths.find_map(|path| path.ok()).map(|path| path.to_string_lossy().to_string()),
+        Err(_) => None,
+    }
+}
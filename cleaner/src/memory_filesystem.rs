@@ -0,0 +1,472 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use glob::{MatchOptions, Pattern, PatternError};
+use crate::filesystem::{FileKind, FileMetadata, FileSystem, GlobFailure, GlobPaths};
+use crate::shred::Overwritten;
+
+// Symlink chains longer than this fail like ELOOP
+const MAX_SYMLINK_DEPTH: usize = 40;
+
+#[derive(Clone)]
+enum MemoryKind {
+    File,
+    Directory,
+    Symlink(PathBuf),
+}
+
+#[derive(Clone)]
+struct MemoryEntry {
+    kind: MemoryKind,
+    len: u64,
+    modified: SystemTime,
+    denied: bool,
+}
+
+// A filesystem kept in memory for deterministic runs of the cleaner.
+// Parents are created on demand, a denied directory can not be listed and nothing
+// inside it can be removed, a denied file can not be removed or overwritten.
+pub struct MemoryFileSystem {
+    entries: Mutex<BTreeMap<PathBuf, MemoryEntry>>,
+}
+
+impl Default for MemoryFileSystem {
+    fn default() -> Self {
+        MemoryFileSystem::new()
+    }
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> MemoryFileSystem {
+        MemoryFileSystem {
+            entries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn add_file(&self, path: impl AsRef<Path>, len: u64, modified: SystemTime) -> &Self {
+        self.insert(path.as_ref(), MemoryKind::File, len, modified)
+    }
+
+    pub fn add_directory(&self, path: impl AsRef<Path>, modified: SystemTime) -> &Self {
+        self.insert(path.as_ref(), MemoryKind::Directory, 0, modified)
+    }
+
+    pub fn add_symlink(&self, path: impl AsRef<Path>, target: impl AsRef<Path>) -> &Self {
+        let len = target.as_ref().as_os_str().len() as u64;
+        self.insert(path.as_ref(), MemoryKind::Symlink(target.as_ref().to_path_buf()), len, SystemTime::UNIX_EPOCH)
+    }
+
+    // Makes every operation that needs permission on the path fail with PermissionDenied
+    pub fn deny(&self, path: impl AsRef<Path>) -> &Self {
+        if let Some(entry) = self.lock().get_mut(path.as_ref()) {
+            entry.denied = true;
+        }
+        self
+    }
+
+    pub fn exists(&self, path: impl AsRef<Path>) -> bool {
+        self.lock().contains_key(path.as_ref())
+    }
+
+    // Every path still present, in sorted order
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.lock().keys().cloned().collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<PathBuf, MemoryEntry>> {
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn insert(&self, path: &Path, kind: MemoryKind, len: u64, modified: SystemTime) -> &Self {
+        let mut entries = self.lock();
+        for ancestor in path.ancestors().skip(1) {
+            if ancestor.as_os_str().is_empty() {
+                break;
+            }
+            entries.entry(ancestor.to_path_buf()).or_insert(MemoryEntry {
+                kind: MemoryKind::Directory,
+                len: 0,
+                modified,
+                denied: false,
+            });
+        }
+        entries.insert(path.to_path_buf(), MemoryEntry { kind, len, modified, denied: false });
+        self
+    }
+
+    fn get(&self, path: &Path) -> io::Result<MemoryEntry> {
+        self.lock().get(path).cloned().ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    fn resolve(&self, path: &Path) -> io::Result<PathBuf> {
+        let mut path = path.to_path_buf();
+        for _ in 0..MAX_SYMLINK_DEPTH {
+            match self.get(&path)?.kind {
+                MemoryKind::Symlink(target) => {
+                    path = match path.parent() {
+                        Some(parent) if target.is_relative() => parent.join(target),
+                        _ => target,
+                    };
+                }
+                _ => return Ok(path),
+            }
+        }
+        Err(io::Error::other("too many levels of symbolic links"))
+    }
+
+    fn children(entries: &BTreeMap<PathBuf, MemoryEntry>, path: &Path) -> Vec<PathBuf> {
+        entries.keys()
+            .filter(|key| key.parent() == Some(path))
+            .cloned()
+            .collect()
+    }
+
+    fn check_removable(entries: &BTreeMap<PathBuf, MemoryEntry>, path: &Path) -> io::Result<()> {
+        let parent_denied = path.parent().and_then(|parent| entries.get(parent)).map(|parent| parent.denied).unwrap_or(false);
+        let file_denied = entries.get(path).map(|entry| entry.denied && !matches!(entry.kind, MemoryKind::Directory)).unwrap_or(false);
+        if parent_denied || file_denied {
+            return Err(io::Error::from(io::ErrorKind::PermissionDenied));
+        }
+        Ok(())
+    }
+
+    fn walk(&self, directory: &Path, components: &[Pattern], options: MatchOptions, results: &mut Vec<Result<PathBuf, GlobFailure>>) {
+        let component = match components.first() {
+            Some(component) => component,
+            None => {
+                results.push(Ok(directory.to_path_buf()));
+                return;
+            }
+        };
+        let children = match self.read_dir(directory) {
+            Ok(children) => children,
+            Err(error) => {
+                results.push(Err(GlobFailure { path: directory.to_path_buf(), error }));
+                return;
+            }
+        };
+        if component.as_str() == "**" {
+            // zero or more directories
+            self.walk(directory, &components[1..], options, results);
+            for child in children {
+                if self.metadata(&child).map(|metadata| metadata.is_dir()).unwrap_or(false) {
+                    self.walk(&child, components, options, results);
+                }
+            }
+            return;
+        }
+        for child in children {
+            let name = match child.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => continue,
+            };
+            if !component.matches_with(&name, options) {
+                continue;
+            }
+            if components.len() == 1 {
+                results.push(Ok(child));
+            }
+            else if self.metadata(&child).map(|metadata| metadata.is_dir()).unwrap_or(false) {
+                self.walk(&child, &components[1..], options, results);
+            }
+        }
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let entry = self.get(path)?;
+        let kind = match entry.kind {
+            MemoryKind::File => FileKind::File,
+            MemoryKind::Directory => FileKind::Directory,
+            MemoryKind::Symlink(_) => FileKind::Symlink,
+        };
+        Ok(FileMetadata {
+            kind,
+            len: entry.len,
+            modified: Some(entry.modified),
+            device: None,
+            inode: None,
+            links: 1,
+            allocated: entry.len,
+        })
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.symlink_metadata(&self.resolve(path)?)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let resolved = self.resolve(path)?;
+        let entries = self.lock();
+        let entry = entries.get(&resolved).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        if entry.denied {
+            return Err(io::Error::from(io::ErrorKind::PermissionDenied));
+        }
+        if !matches!(entry.kind, MemoryKind::Directory) {
+            return Err(io::Error::from(io::ErrorKind::NotADirectory));
+        }
+        // children are reported below the path that was asked for, like std::fs::read_dir
+        Ok(Self::children(&entries, &resolved).iter()
+            .filter_map(|child| child.file_name().map(|name| path.join(name)))
+            .collect())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.resolve(path)
+    }
+
+    fn glob(&self, pattern: &str, options: MatchOptions) -> Result<GlobPaths<'_>, PatternError> {
+        let mut root = PathBuf::new();
+        let mut components = vec![];
+        for component in Path::new(pattern).components() {
+            match component {
+                Component::Prefix(_) | Component::RootDir => root.push(component),
+                _ => components.push(Pattern::new(&component.as_os_str().to_string_lossy())?),
+            }
+        }
+        let mut results = vec![];
+        if !components.is_empty() || self.exists(&root) {
+            self.walk(&root, &components, options, &mut results);
+        }
+        Ok(Box::new(results.into_iter()))
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        let entry = entries.get(path).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        if matches!(entry.kind, MemoryKind::Directory) {
+            return Err(io::Error::from(io::ErrorKind::IsADirectory));
+        }
+        Self::check_removable(&entries, path)?;
+        entries.remove(path);
+        Ok(())
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        let entry = entries.get(path).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        if !matches!(entry.kind, MemoryKind::Directory) {
+            return Err(io::Error::from(io::ErrorKind::NotADirectory));
+        }
+        Self::check_removable(&entries, path)?;
+        if !Self::children(&entries, path).is_empty() {
+            return Err(io::Error::from(io::ErrorKind::DirectoryNotEmpty));
+        }
+        entries.remove(path);
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        let entry = entries.get(path).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        if !matches!(entry.kind, MemoryKind::Directory) {
+            return Err(io::Error::from(io::ErrorKind::NotADirectory));
+        }
+        // deepest entries first, children sort after their parent
+        let descendants: Vec<PathBuf> = entries.keys()
+            .filter(|key| key.starts_with(path) && key.as_path() != path)
+            .rev()
+            .cloned()
+            .collect();
+        for descendant in descendants {
+            Self::check_removable(&entries, &descendant)?;
+            if !Self::children(&entries, &descendant).is_empty() {
+                return Err(io::Error::from(io::ErrorKind::DirectoryNotEmpty));
+            }
+            entries.remove(&descendant);
+        }
+        Self::check_removable(&entries, path)?;
+        entries.remove(path);
+        Ok(())
+    }
+
+    // there are no hard links in memory, every file is overwritten
+    fn overwrite(&self, path: &Path, passes: u32) -> io::Result<Overwritten> {
+        let entry = self.get(path)?;
+        match entry.kind {
+            MemoryKind::Directory => {
+                let mut overwritten = Overwritten::default();
+                for child in self.read_dir(path)? {
+                    overwritten.written += self.overwrite(&child, passes)?.written;
+                }
+                Ok(overwritten)
+            }
+            MemoryKind::Symlink(_) => Ok(Overwritten::default()),
+            MemoryKind::File => {
+                if entry.denied {
+                    return Err(io::Error::from(io::ErrorKind::PermissionDenied));
+                }
+                let written = entry.len * passes.max(1) as u64;
+                if let Some(entry) = self.lock().get_mut(path) {
+                    entry.len = 0;
+                }
+                Ok(Overwritten { written, hard_linked: vec![] })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use database::rule_files::{parse_rules, Placeholders};
+    use database::structures::{CleanerData, CleanerErrorKind, CleanerOperation};
+    use super::*;
+    use crate::quarantine::Quarantine;
+    use crate::{clear_data, CleanerOptions};
+
+    // A built-in rule and the part of its path before `suffix`, the home directory
+    // depends on the user running the tests
+    fn rule(program: &str, category: &str, suffix: &str) -> (CleanerData, String) {
+        let rules = match parse_rules("unix.toml", include_str!("../../database/rules/unix.toml"), &Placeholders::current()) {
+            Ok(rules) => rules,
+            Err(error) => panic!("{}", error),
+        };
+        let data = rules.into_iter()
+            .find(|data| data.program == program && data.category == category && data.path.ends_with(suffix))
+            .unwrap();
+        let root = data.path.strip_suffix(suffix).unwrap().to_string();
+        (data, root)
+    }
+
+    fn options(filesystem: &Arc<MemoryFileSystem>) -> CleanerOptions {
+        CleanerOptions {
+            filesystem: Some(filesystem.clone()),
+            ..CleanerOptions::default()
+        }
+    }
+
+    fn days(count: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(count * 24 * 60 * 60)
+    }
+
+    #[test]
+    fn minecraft_logs_keep_latest_log() {
+        let (data, root) = rule("MultiMC", "Logs", "/instances/**/minecraft/logs/*");
+        let logs = format!("{}/instances/Vanilla/minecraft/logs", root);
+        let filesystem = Arc::new(MemoryFileSystem::new());
+        filesystem
+            .add_file(format!("{}/latest.log", logs), 100, days(10))
+            .add_file(format!("{}/2024-01-01-1.log.gz", logs), 200, days(9))
+            .add_file(format!("{}/debug.log", logs), 50, days(8))
+            .add_file(format!("{}/archive/old.log", logs), 30, days(7));
+
+        let result = clear_data(&data, &options(&filesystem));
+
+        assert!(result.errors.is_empty());
+        // the removed archive directory counts as a folder, not by its files
+        assert_eq!(result.files, 2);
+        assert_eq!(result.folders, 1);
+        assert_eq!(result.bytes, 280);
+        assert!(result.working);
+        assert!(filesystem.exists(format!("{}/latest.log", logs)));
+        assert!(!filesystem.exists(format!("{}/2024-01-01-1.log.gz", logs)));
+        assert!(!filesystem.exists(format!("{}/debug.log", logs)));
+        assert!(!filesystem.exists(format!("{}/archive", logs)));
+    }
+
+    #[test]
+    fn rule_exclusions_ignore_case_with_the_rule() {
+        let (mut data, root) = rule("MultiMC", "Logs", "/instances/**/minecraft/logs/*");
+        data.case_insensitive = true;
+        let logs = format!("{}/instances/Vanilla/minecraft/logs", root);
+        let filesystem = Arc::new(MemoryFileSystem::new());
+        filesystem
+            .add_file(format!("{}/Latest.log", logs), 100, days(10))
+            .add_file(format!("{}/debug.log", logs), 50, days(8));
+
+        let result = clear_data(&data, &options(&filesystem));
+
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files, 1);
+        assert!(filesystem.exists(format!("{}/Latest.log", logs)));
+        assert!(!filesystem.exists(format!("{}/debug.log", logs)));
+    }
+
+    #[test]
+    fn crash_reports_keep_the_five_newest() {
+        let (data, root) = rule("MultiMC", "Logs", "/instances/**/minecraft/crash-reports/*");
+        let reports = format!("{}/instances/Modded/minecraft/crash-reports", root);
+        let filesystem = Arc::new(MemoryFileSystem::new());
+        for day in 1..=7 {
+            filesystem.add_file(format!("{}/crash-{}.txt", reports, day), 10, days(day));
+        }
+
+        let result = clear_data(&data, &options(&filesystem));
+
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files, 2);
+        assert_eq!(result.bytes, 20);
+        let remaining: Vec<PathBuf> = (3..=7).map(|day| PathBuf::from(format!("{}/crash-{}.txt", reports, day))).collect();
+        let present: Vec<PathBuf> = filesystem.paths().into_iter().filter(|path| path.starts_with(&reports) && path != Path::new(&reports)).collect();
+        assert_eq!(present, remaining);
+    }
+
+    #[test]
+    fn named_files_leave_the_rest_of_the_profile() {
+        let (data, root) = rule("LibreWolf", "LastActivity", "/.librewolf/**/");
+        let profile = format!("{}/.librewolf/abcd.default", root);
+        let filesystem = Arc::new(MemoryFileSystem::new());
+        filesystem
+            .add_file(format!("{}/favicons.sqlite", profile), 40, days(1))
+            .add_file(format!("{}/formhistory.sqlite", profile), 60, days(1))
+            .add_file(format!("{}/places.sqlite", profile), 500, days(1));
+
+        let result = clear_data(&data, &options(&filesystem));
+
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files, 2);
+        assert_eq!(result.bytes, 100);
+        assert!(filesystem.exists(format!("{}/places.sqlite", profile)));
+        assert!(!filesystem.exists(format!("{}/favicons.sqlite", profile)));
+        assert!(!filesystem.exists(format!("{}/formhistory.sqlite", profile)));
+    }
+
+    #[test]
+    fn denied_files_are_reported_and_the_rest_removed() {
+        let (data, root) = rule("Discord", "Logs", "/discord/logs/*");
+        let logs = format!("{}/discord/logs", root);
+        let filesystem = Arc::new(MemoryFileSystem::new());
+        filesystem
+            .add_file(format!("{}/renderer.log", logs), 70, days(1))
+            .add_file(format!("{}/utility.log", logs), 30, days(1))
+            .deny(format!("{}/utility.log", logs));
+
+        let result = clear_data(&data, &options(&filesystem));
+
+        assert_eq!(result.files, 1);
+        assert_eq!(result.bytes, 70);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].path, format!("{}/utility.log", logs));
+        assert!(result.errors[0].operation == CleanerOperation::RemoveFile);
+        assert!(result.errors[0].kind == CleanerErrorKind::PermissionDenied);
+        assert!(!filesystem.exists(format!("{}/renderer.log", logs)));
+        assert!(filesystem.exists(format!("{}/utility.log", logs)));
+    }
+
+    #[test]
+    fn quarantine_is_refused() {
+        let (data, root) = rule("Discord", "Logs", "/discord/logs/*");
+        let filesystem = Arc::new(MemoryFileSystem::new());
+        filesystem.add_file(format!("{}/discord/logs/renderer.log", root), 70, days(1));
+        // never the user's quarantine directory, the test must not leave runs behind
+        let quarantine_root = std::env::temp_dir().join(format!("winbooster-memory-{}-quarantine", std::process::id()));
+        let options = CleanerOptions {
+            quarantine: Some(Arc::new(Quarantine::create_in(&quarantine_root).unwrap())),
+            ..options(&filesystem)
+        };
+
+        let result = clear_data(&data, &options);
+        let _ = std::fs::remove_dir_all(&quarantine_root);
+
+        assert_eq!(result.files, 0);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].operation == CleanerOperation::Quarantine);
+        assert!(result.errors[0].kind == CleanerErrorKind::Unsupported);
+        assert!(filesystem.exists(format!("{}/discord/logs/renderer.log", root)));
+    }
+}
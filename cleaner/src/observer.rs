@@ -0,0 +1,21 @@
+#[derive(Clone, Copy, PartialEq)]
+pub enum CleanerEventKind {
+    Scanned,
+    Removed,
+    Skipped,
+    Failed,
+    // waiting for a running program to close, path is the program name
+    Waiting,
+}
+
+pub struct CleanerEvent<'a> {
+    pub kind: CleanerEventKind,
+    pub path: &'a str,
+    pub bytes: u64,
+}
+
+// Receives an event for every file and directory a rule touches. Called from the worker
+// threads, so implementations should be cheap and must not block.
+pub trait CleanerObserver: Send + Sync {
+    fn notify(&self, event: &CleanerEvent);
+}
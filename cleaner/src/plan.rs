@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+use std::thread;
+use std::time::Duration;
+use database::structures::{CleanerData, CleanerError, CleanerLock, CleanerSkip, CleanerSkipReason};
+use crate::observer::{CleanerEvent, CleanerEventKind};
+use crate::pool::map_pool;
+use crate::processes::{find_running_programs, RunningProgram, RunningProgramPolicy};
+use crate::{plan_data, CleanerOptions};
+
+// A path resolved from a rule, with the counts credited to the rule once it is removed
+#[derive(Clone)]
+pub struct PlannedPath {
+    pub path: String,
+    pub is_dir: bool,
+    pub files: u64,
+    pub folders: u64,
+}
+
+pub struct CleanerPlan {
+    pub data: CleanerData,
+    pub paths: Vec<PlannedPath>,
+    pub errors: Vec<CleanerError>,
+    pub skipped: Vec<CleanerSkip>,
+}
+
+// Expands every rule and removes paths that another rule already covers,
+// so each file is handled and counted exactly once
+pub fn plan_rules(database: &[CleanerData], options: &CleanerOptions) -> Vec<CleanerPlan> {
+    let running = match options.running_programs {
+        RunningProgramPolicy::Ignore => vec![],
+        RunningProgramPolicy::Skip => find_running_programs(database),
+        RunningProgramPolicy::Wait => wait_for_programs(database, options),
+    };
+
+    let mut plans = map_pool(database, options.concurrency, |data| {
+        if running.iter().any(|program| program.program == data.program) {
+            return skip_plan(data);
+        }
+        plan_data(data, options)
+    });
+    for program in &running {
+        // one warning per program is enough
+        if let Some(plan) = plans.iter_mut().find(|plan| plan.data.program == program.program) {
+            plan.skipped.push(CleanerSkip {
+                path: program.describe(),
+                reason: CleanerSkipReason::ProgramRunning,
+            });
+        }
+    }
+    deduplicate(&mut plans);
+    plans
+}
+
+fn skip_plan(data: &CleanerData) -> CleanerPlan {
+    CleanerPlan {
+        data: data.clone(),
+        paths: vec![],
+        errors: vec![],
+        skipped: vec![],
+    }
+}
+
+// Polls until none of the programs runs anymore. Returns the programs still running
+// when the run was cancelled, they are skipped like with RunningProgramPolicy::Skip.
+fn wait_for_programs(database: &[CleanerData], options: &CleanerOptions) -> Vec<RunningProgram> {
+    loop {
+        let running = find_running_programs(database);
+        if running.is_empty() || options.cancellation.is_cancelled() {
+            return running;
+        }
+        if let Some(observer) = &options.observer {
+            for program in &running {
+                observer.notify(&CleanerEvent { kind: CleanerEventKind::Waiting, path: &program.program, bytes: 0 });
+            }
+        }
+        thread::sleep(Duration::from_secs(1));
+    }
+}
+
+// Keeps the first occurrence of a path and drops everything inside a directory that is
+// removed as a whole, no matter which rule scheduled it first
+pub fn deduplicate(plans: &mut [CleanerPlan]) {
+    let directories: HashSet<String> = plans.iter()
+        .flat_map(|plan| &plan.paths)
+        .filter(|planned| planned.is_dir)
+        .map(|planned| get_key(&planned.path))
+        .collect();
+
+    let mut seen: HashSet<String> = HashSet::new();
+    for plan in plans.iter_mut() {
+        plan.paths.retain(|planned| {
+            let key = get_key(&planned.path);
+            if has_scheduled_parent(&key, &directories) {
+                return false;
+            }
+            seen.insert(key)
+        });
+    }
+}
+
+fn has_scheduled_parent(key: &str, directories: &HashSet<String>) -> bool {
+    key.match_indices('/').any(|(index, _)| index > 0 && directories.contains(&key[..index]))
+}
+
+fn get_key(path: &str) -> String {
+    let key = path.replace('\\', "/").trim_end_matches('/').to_string();
+    if cfg!(windows) {
+        key.to_lowercase()
+    }
+    else {
+        key
+    }
+}
+
+// Plans that only contain the paths which were locked during the previous run
+pub fn plan_retry(plans: &[CleanerPlan], locked: &[CleanerLock]) -> Vec<CleanerPlan> {
+    plans.iter()
+        .map(|plan| CleanerPlan {
+            data: plan.data.clone(),
+            paths: plan.paths.iter().filter(|planned| locked.iter().any(|lock| lock.path == planned.path)).cloned().collect(),
+            errors: vec![],
+            skipped: vec![],
+        })
+        .filter(|plan| !plan.paths.is_empty())
+        .collect()
+}
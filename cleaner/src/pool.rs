@@ -0,0 +1,105 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use database::structures::CleanerData;
+
+pub enum PoolEvent<'a, T, R> {
+    Started(&'a T),
+    Finished(&'a T, R),
+}
+
+pub fn default_concurrency() -> usize {
+    thread::available_parallelism().map(|count| count.get()).unwrap_or(4).min(8)
+}
+
+// Runs job for every item on at most `concurrency` blocking worker threads, 0 picks default_concurrency().
+// Events are delivered on the calling thread, in the order workers report them.
+pub fn run_pool<T, R, J, E>(items: &[T], concurrency: usize, job: J, mut on_event: E)
+where
+    T: Sync,
+    R: Send,
+    J: Fn(&T) -> R + Sync,
+    E: FnMut(PoolEvent<T, R>),
+{
+    run_indexed(items, concurrency, job, |index, result| match result {
+        Some(result) => on_event(PoolEvent::Finished(&items[index], result)),
+        None => on_event(PoolEvent::Started(&items[index])),
+    });
+}
+
+// Like run_pool, but returns the results in the order of the items
+pub fn map_pool<T, R, J>(items: &[T], concurrency: usize, job: J) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    J: Fn(&T) -> R + Sync,
+{
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    run_indexed(items, concurrency, job, |index, result| {
+        if result.is_some() {
+            results[index] = result;
+        }
+    });
+    results.into_iter().flatten().collect()
+}
+
+fn run_indexed<T, R, J, E>(items: &[T], concurrency: usize, job: J, mut on_event: E)
+where
+    T: Sync,
+    R: Send,
+    J: Fn(&T) -> R + Sync,
+    E: FnMut(usize, Option<R>),
+{
+    let concurrency = if concurrency == 0 { default_concurrency() } else { concurrency };
+    let workers = concurrency.min(items.len());
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, Option<R>)>();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let next = &next;
+            let job = &job;
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    if index >= items.len() {
+                        break;
+                    }
+                    let _ = sender.send((index, None));
+                    let result = job(&items[index]);
+                    let _ = sender.send((index, Some(result)));
+                }
+            });
+        }
+        drop(sender);
+
+        for (index, result) in receiver {
+            on_event(index, result);
+        }
+    });
+}
+
+// Interleaves rules of different programs, so a program with hundreds of rules
+// does not occupy every worker while the others wait
+pub fn fair_order(database: Vec<CleanerData>) -> Vec<CleanerData> {
+    let mut queues: Vec<(String, VecDeque<CleanerData>)> = vec![];
+    for data in database {
+        match queues.iter_mut().find(|(program, _)| *program == data.program) {
+            Some((_, queue)) => queue.push_back(data),
+            None => queues.push((data.program.clone(), VecDeque::from([data]))),
+        }
+    }
+
+    let mut ordered = vec![];
+    while !queues.is_empty() {
+        for (_, queue) in queues.iter_mut() {
+            if let Some(data) = queue.pop_front() {
+                ordered.push(data);
+            }
+        }
+        queues.retain(|(_, queue)| !queue.is_empty());
+    }
+    ordered
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use database::structures::CleanerData;
+use crate::filesystem::FileSystem;
+
+#[derive(Clone)]
+pub struct RunningProcess {
+    pub pid: u32,
+    pub name: String,
+    // every name the process is known by: executable, argv[0] and comm
+    names: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct RunningProgram {
+    pub program: String,
+    pub processes: Vec<RunningProcess>,
+}
+
+// What the engine does with rules of a program that is running while it cleans
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum RunningProgramPolicy {
+    #[default]
+    Skip,
+    Wait,
+    Ignore,
+}
+
+// Programs of the given rules that have at least one process running right now
+pub fn find_running_programs(database: &[CleanerData]) -> Vec<RunningProgram> {
+    let processes = list_processes();
+    let mut running: Vec<RunningProgram> = vec![];
+    for data in database {
+        if running.iter().any(|program| program.program == data.program) {
+            continue;
+        }
+        if data.processes.is_empty() {
+            continue;
+        }
+        let matching: Vec<RunningProcess> = processes.iter()
+            .filter(|process| process.names.iter().any(|name| data.processes.contains(name)))
+            .cloned()
+            .collect();
+        if !matching.is_empty() {
+            running.push(RunningProgram {
+                program: data.program.clone(),
+                processes: matching,
+            });
+        }
+    }
+    running
+}
+
+impl RunningProgram {
+    // "Discord (1234 discord, 1240 discord)"
+    pub fn describe(&self) -> String {
+        format!("{} ({})", self.program, describe_processes(&self.processes))
+    }
+}
+
+// "1234 discord, 1240 discord"
+pub fn describe_processes<'a>(processes: impl IntoIterator<Item = &'a RunningProcess>) -> String {
+    let processes: Vec<String> = processes.into_iter().map(|process| format!("{} {}", process.pid, process.name)).collect();
+    processes.join(", ")
+}
+
+// Files held open by other processes, taken once per run. Every open file and each of its parent
+// directories maps to the processes holding something at or below it, so a lookup is a single probe.
+#[derive(Default)]
+pub struct OpenFiles {
+    processes: Vec<RunningProcess>,
+    holders: HashMap<PathBuf, Vec<usize>>,
+}
+
+impl OpenFiles {
+    #[cfg(target_os = "linux")]
+    pub fn snapshot() -> OpenFiles {
+        use std::fs;
+
+        let mut open_files = OpenFiles::default();
+        for (directory, pid) in list_process_directories() {
+            // only readable for processes of the same user, root sees everything
+            let descriptors = match fs::read_dir(directory.join("fd")) {
+                Ok(descriptors) => descriptors,
+                Err(_) => continue,
+            };
+            let mut process = None;
+            for descriptor in descriptors.flatten() {
+                let target = match fs::read_link(descriptor.path()) {
+                    Ok(target) => target,
+                    Err(_) => continue,
+                };
+                // sockets, pipes and anonymous inodes look like "socket:[1234]"
+                if !target.is_absolute() {
+                    continue;
+                }
+                if process.is_none() {
+                    process = read_process(&directory, pid).map(|process| {
+                        open_files.processes.push(process);
+                        open_files.processes.len() - 1
+                    });
+                }
+                if let Some(process) = process {
+                    open_files.add(&target, process);
+                }
+            }
+        }
+        open_files
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn snapshot() -> OpenFiles {
+        OpenFiles::default()
+    }
+
+    fn add(&mut self, path: &Path, process: usize) {
+        for ancestor in path.ancestors() {
+            let holders = self.holders.entry(ancestor.to_path_buf()).or_default();
+            // reached through another file of the process, so are the directories above
+            if holders.contains(&process) {
+                break;
+            }
+            holders.push(process);
+        }
+    }
+
+    // Processes holding the path, or for a directory anything below it, open
+    pub fn holders(&self, filesystem: &dyn FileSystem, path: &Path) -> Vec<&RunningProcess> {
+        if self.holders.is_empty() {
+            return vec![];
+        }
+        let path = filesystem.canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        match self.holders.get(&path) {
+            Some(processes) => processes.iter().map(|process| &self.processes[*process]).collect(),
+            None => vec![],
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn list_processes() -> Vec<RunningProcess> {
+    list_process_directories()
+        .into_iter()
+        .filter_map(|(directory, pid)| read_process(&directory, pid))
+        .collect()
+}
+
+// /proc/<pid> of every process except this one
+#[cfg(target_os = "linux")]
+fn list_process_directories() -> Vec<(PathBuf, u32)> {
+    let own_pid = std::process::id();
+    let entries = match std::fs::read_dir("/proc") {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+    entries.flatten()
+        .filter_map(|entry| match entry.file_name().to_string_lossy().parse::<u32>() {
+            Ok(pid) if pid != own_pid => Some((entry.path(), pid)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn read_process(directory: &Path, pid: u32) -> Option<RunningProcess> {
+    use std::fs;
+
+    let mut names = vec![];
+    // exe is only readable for processes of the same user, cmdline and comm work for everyone
+    if let Ok(exe) = fs::read_link(directory.join("exe")) {
+        names.push(normalize_name(&exe.to_string_lossy()));
+    }
+    if let Ok(cmdline) = fs::read(directory.join("cmdline"))
+        && let Some(argument) = cmdline.split(|byte| *byte == 0).next().filter(|argument| !argument.is_empty()) {
+        names.push(normalize_name(&String::from_utf8_lossy(argument)));
+    }
+    if let Ok(comm) = fs::read_to_string(directory.join("comm")) {
+        names.push(normalize_name(comm.trim()));
+    }
+    if names.is_empty() {
+        return None;
+    }
+    names.dedup();
+    Some(RunningProcess {
+        pid,
+        name: names[0].clone(),
+        names,
+    })
+}
+
+#[cfg(windows)]
+pub fn list_processes() -> Vec<RunningProcess> {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    // CREATE_NO_WINDOW, the GUI must not flash a console
+    let output = match Command::new("tasklist").args(["/fo", "csv", "/nh"]).creation_flags(0x08000000).output() {
+        Ok(output) => output,
+        Err(_) => return vec![],
+    };
+    let mut processes = vec![];
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let fields: Vec<&str> = line.split("\",\"").map(|field| field.trim_matches('"')).collect();
+        if fields.len() < 2 {
+            continue;
+        }
+        let pid: u32 = match fields[1].parse() {
+            Ok(pid) => pid,
+            Err(_) => continue,
+        };
+        let name = normalize_name(fields[0]);
+        processes.push(RunningProcess {
+            pid,
+            name: name.clone(),
+            names: vec![name],
+        });
+    }
+    processes
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+pub fn list_processes() -> Vec<RunningProcess> {
+    vec![]
+}
+
+// "/usr/lib/Discord/Discord" and "C:\...\Discord.exe" both become "discord"
+fn normalize_name(name: &str) -> String {
+    let name = name.strip_suffix(" (deleted)").unwrap_or(name);
+    let file_name = name.rsplit(['/', '\\']).next().unwrap_or(name).to_lowercase();
+    file_name.strip_suffix(".exe").map(str::to_string).unwrap_or(file_name)
+}
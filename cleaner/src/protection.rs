@@ -0,0 +1,148 @@
+use std::env;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use database::utils::get_winbooster_directory;
+
+// Directories below a filesystem or drive root that must never be removed as a whole
+const PROTECTED_DIRECTORIES: [&str; 15] = [
+    "home", "users", "root", "var", "opt", "srv", "mnt", "media",
+    "windows", "program files", "program files (x86)", "programdata", "recovery", "$recycle.bin", "system volume information",
+];
+
+// Directories below a filesystem or drive root that must not be touched at all
+const PROTECTED_TREES: [&str; 14] = [
+    "etc", "usr", "bin", "sbin", "lib", "lib32", "lib64", "boot", "dev", "proc", "sys", "run",
+    "windows/system32", "windows/syswow64",
+];
+
+// Directories inside a home directory that must never be removed as a whole
+const PROTECTED_HOME_DIRECTORIES: [&str; 15] = [
+    ".cache", ".config", ".local", ".local/share", ".ssh",
+    "appdata", "appdata/local", "appdata/locallow", "appdata/roaming",
+    "desktop", "documents", "downloads", "music", "pictures", "videos",
+];
+
+pub fn get_protected_file() -> PathBuf {
+    get_winbooster_directory().join("protected.txt")
+}
+
+// User additions to the deny-list, everything inside them is protected too
+pub fn load_protected_paths() -> Vec<PathBuf> {
+    match fs::read_to_string(get_protected_file()) {
+        Ok(content) => content.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(PathBuf::from)
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+// Rejects patterns produced from empty parts, like "/home//.cache" for an empty username
+// or "\steamapps\..." for a missing Steam directory
+pub fn is_well_formed_pattern(pattern: &str) -> bool {
+    // a trailing separator only limits the matches to directories, like "**/"
+    let pattern = pattern.strip_suffix(['/', '\\']).unwrap_or(pattern);
+    if !Path::new(pattern).is_absolute() {
+        return false;
+    }
+    let mut parts = pattern.split(['/', '\\']);
+    if cfg!(not(windows)) {
+        // the leading separator of an absolute path
+        parts.next();
+    }
+    parts.all(|part| !part.is_empty() && part != "." && part != "..")
+}
+
+// Rule entries such as files_to_remove are appended to the matched path and must stay below it
+pub fn is_well_formed_entry(entry: &str) -> bool {
+    !entry.is_empty() && entry.split(['/', '\\']).all(|part| !part.is_empty() && part != "." && part != "..")
+}
+
+pub fn is_protected(path: &Path, user_paths: &[PathBuf]) -> bool {
+    let parts = match get_parts(path) {
+        Some(parts) => parts,
+        None => return true,
+    };
+    if parts.is_empty() {
+        // filesystem or drive root
+        return true;
+    }
+    let relative = parts.join("/");
+    if PROTECTED_DIRECTORIES.contains(&relative.as_str()) {
+        return true;
+    }
+    if PROTECTED_TREES.iter().any(|tree| relative == *tree || relative.starts_with(&(tree.to_string() + "/"))) {
+        return true;
+    }
+    if parts[0] == "home" || parts[0] == "users" {
+        if parts.len() == 2 {
+            return true;
+        }
+        if parts.len() > 2 && PROTECTED_HOME_DIRECTORIES.contains(&parts[2..].join("/").as_str()) {
+            return true;
+        }
+    }
+    if let Some(home_parts) = get_home_directory().and_then(|home| get_parts(&home)) {
+        if parts == home_parts {
+            return true;
+        }
+        if parts.starts_with(&home_parts) && PROTECTED_HOME_DIRECTORIES.contains(&parts[home_parts.len()..].join("/").as_str()) {
+            return true;
+        }
+    }
+    user_paths.iter().any(|user_path| match get_parts(user_path) {
+        Some(user_parts) => parts.starts_with(&user_parts) && same_root(path, user_path),
+        None => false,
+    })
+}
+
+// Normalized components below the root, None for paths that are not absolute
+fn get_parts(path: &Path) -> Option<Vec<String>> {
+    if !path.is_absolute() {
+        return None;
+    }
+    let mut parts: Vec<String> = vec![];
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => {
+                // the cleaner joins paths with backslashes, split them on unix too
+                for part in part.to_string_lossy().split('\\') {
+                    match part {
+                        "" | "." => {}
+                        ".." => {
+                            parts.pop();
+                        }
+                        _ => parts.push(normalize(part)),
+                    }
+                }
+            }
+            Component::ParentDir => {
+                parts.pop();
+            }
+            _ => {}
+        }
+    }
+    Some(parts)
+}
+
+fn same_root(path: &Path, other: &Path) -> bool {
+    let prefix = |path: &Path| match path.components().next() {
+        Some(Component::Prefix(prefix)) => Some(normalize(&prefix.as_os_str().to_string_lossy())),
+        _ => None,
+    };
+    prefix(path) == prefix(other)
+}
+
+// Compared case-insensitively, protecting too much is fine
+fn normalize(part: &str) -> String {
+    part.to_lowercase()
+}
+
+fn get_home_directory() -> Option<PathBuf> {
+    let variable = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    match env::var(variable) {
+        Ok(home) if !home.is_empty() => Some(PathBuf::from(home)),
+        _ => None,
+    }
+}
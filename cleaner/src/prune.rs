@@ -0,0 +1,45 @@
+use std::path::{Component, Path, PathBuf};
+use crate::filesystem::FileSystem;
+
+// The literal part of a rule pattern, "C:\Games\instances\*\logs\*" gives "C:\Games\instances".
+// A pattern without wildcards is its own root.
+pub fn glob_root(pattern: &str) -> PathBuf {
+    let mut root = PathBuf::new();
+    for component in Path::new(pattern).components() {
+        if let Component::Normal(part) = component && part.to_string_lossy().contains(['*', '?', '[']) {
+            break;
+        }
+        root.push(component);
+    }
+    root
+}
+
+// Removes the parents of a removed path while they are empty, stopping below root.
+// `keep` is asked before every directory, returns the removed directories.
+pub fn prune_empty_parents(filesystem: &dyn FileSystem, path: &Path, root: &Path, keep: impl Fn(&Path) -> bool) -> Vec<PathBuf> {
+    let mut pruned = vec![];
+    for parent in path.ancestors().skip(1) {
+        if parent == root || !parent.starts_with(root) || keep(parent) {
+            break;
+        }
+        let is_directory = match filesystem.symlink_metadata(parent) {
+            Ok(metadata) => metadata.is_dir(),
+            Err(_) => false,
+        };
+        if !is_directory || !is_empty(filesystem, parent) {
+            break;
+        }
+        match filesystem.remove_dir(parent) {
+            Ok(_) => pruned.push(parent.to_path_buf()),
+            Err(_) => break,
+        }
+    }
+    pruned
+}
+
+fn is_empty(filesystem: &dyn FileSystem, path: &Path) -> bool {
+    match filesystem.read_dir(path) {
+        Ok(entries) => entries.is_empty(),
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,311 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+use serde::{Deserialize, Serialize};
+use database::structures::{CleanerData, CleanerError, CleanerErrorKind, CleanerOperation};
+use database::utils::{format_timestamp, get_winbooster_directory, parse_timestamp};
+
+pub const QUARANTINE_EXPIRY_DAYS: u64 = 14;
+
+const MANIFEST_FILE: &str = "manifest.jsonl";
+const FILES_DIRECTORY: &str = "files";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct QuarantineEntry {
+    pub id: u64,
+    pub original_path: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub modified: u64,
+    pub program: String,
+    pub category: String,
+}
+
+pub struct QuarantineRun {
+    pub run_id: String,
+    pub created: u64,
+    pub entries: Vec<QuarantineEntry>,
+}
+
+pub struct RestoreResult {
+    pub restored: Vec<QuarantineEntry>,
+    pub errors: Vec<CleanerError>,
+}
+
+pub struct Quarantine {
+    run_id: String,
+    directory: PathBuf,
+    next_id: AtomicU64,
+    manifest: Mutex<File>,
+}
+
+impl Quarantine {
+    pub fn create() -> io::Result<Quarantine> {
+        Quarantine::create_in(&get_quarantine_directory())
+    }
+
+    // A new run below `root` instead of the user's quarantine directory
+    pub fn create_in(root: &Path) -> io::Result<Quarantine> {
+        let timestamp = format_timestamp(unix_now()).replace(['-', ':'], "").replace(' ', "-");
+        let mut run_id = timestamp.clone();
+        let mut directory = root.join(&run_id);
+        let mut suffix = 1;
+        while directory.exists() {
+            suffix += 1;
+            run_id = format!("{}-{}", timestamp, suffix);
+            directory = root.join(&run_id);
+        }
+        fs::create_dir_all(directory.join(FILES_DIRECTORY))?;
+        let manifest = OpenOptions::new().create(true).append(true).open(directory.join(MANIFEST_FILE))?;
+
+        Ok(Quarantine {
+            run_id,
+            directory,
+            next_id: AtomicU64::new(1),
+            manifest: Mutex::new(manifest),
+        })
+    }
+
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    pub fn store(&self, path: &Path, size: u64, data: &CleanerData) -> io::Result<()> {
+        let metadata = fs::symlink_metadata(path)?;
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let stored_path = self.directory.join(FILES_DIRECTORY).join(id.to_string());
+        let moved = move_path(path, &stored_path);
+        if moved.is_err() && fs::symlink_metadata(&stored_path).is_err() {
+            return moved;
+        }
+
+        let entry = QuarantineEntry {
+            id,
+            original_path: path.to_string_lossy().to_string(),
+            is_dir: metadata.is_dir(),
+            size,
+            modified: metadata.modified().map(system_time_to_secs).unwrap_or(0),
+            program: data.program.clone(),
+            category: data.category.clone(),
+        };
+        let line = serde_json::to_string(&entry).map_err(io::Error::other)?;
+        let mut manifest = self.manifest.lock().unwrap();
+        writeln!(manifest, "{}", line)?;
+        moved
+    }
+}
+
+pub fn get_quarantine_directory() -> PathBuf {
+    get_winbooster_directory().join("quarantine")
+}
+
+pub fn list_runs() -> Vec<QuarantineRun> {
+    let mut runs = vec![];
+    let entries = match fs::read_dir(get_quarantine_directory()) {
+        Ok(entries) => entries,
+        Err(_) => return runs,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let run_id = entry.file_name().to_string_lossy().to_string();
+        // not the directory's modification time, restoring part of a run writes into it
+        let created = match parse_run_created(&run_id) {
+            Some(created) => created,
+            None => continue,
+        };
+        runs.push(QuarantineRun {
+            run_id,
+            created,
+            entries: read_manifest(&path),
+        });
+    }
+    runs.sort_by(|a, b| a.run_id.cmp(&b.run_id));
+    runs
+}
+
+pub fn restore(run_id: &str, ids: &[u64]) -> RestoreResult {
+    let directory = get_quarantine_directory().join(run_id);
+    let mut result = RestoreResult { restored: vec![], errors: vec![] };
+    let mut remaining = vec![];
+
+    for entry in read_manifest(&directory) {
+        if !ids.is_empty() && !ids.contains(&entry.id) {
+            remaining.push(entry);
+            continue;
+        }
+        let original_path = Path::new(&entry.original_path);
+        let stored_path = directory.join(FILES_DIRECTORY).join(entry.id.to_string());
+        if fs::symlink_metadata(original_path).is_ok() {
+            result.errors.push(CleanerError {
+                path: entry.original_path.clone(),
+                operation: CleanerOperation::Restore,
+                kind: CleanerErrorKind::Other,
+            });
+            remaining.push(entry);
+            continue;
+        }
+        let restored = match original_path.parent() {
+            Some(parent) => fs::create_dir_all(parent).and_then(|_| move_path(&stored_path, original_path)),
+            None => move_path(&stored_path, original_path),
+        };
+        match restored {
+            Ok(_) => result.restored.push(entry),
+            Err(error) => {
+                result.errors.push(CleanerError {
+                    path: entry.original_path.clone(),
+                    operation: CleanerOperation::Restore,
+                    kind: CleanerErrorKind::from_io_error(&error),
+                });
+                remaining.push(entry);
+            }
+        }
+    }
+
+    if remaining.is_empty() {
+        let _ = fs::remove_dir_all(&directory);
+    }
+    else if !result.restored.is_empty() {
+        let _ = write_manifest(&directory, &remaining);
+    }
+    result
+}
+
+pub fn expire_runs(max_age: Duration) -> usize {
+    let now = unix_now();
+    let mut expired = 0;
+    for run in list_runs() {
+        if now.saturating_sub(run.created) > max_age.as_secs() && fs::remove_dir_all(get_quarantine_directory().join(&run.run_id)).is_ok() {
+            expired += 1;
+        }
+    }
+    expired
+}
+
+// Run ids are the UTC creation time, "20250131-235959", followed by "-2", "-3", ... when another
+// run was created within the same second
+fn parse_run_created(run_id: &str) -> Option<u64> {
+    let timestamp = run_id.get(..15)?;
+    let created = parse_timestamp(&format!(
+        "{}-{}-{} {}:{}:{}",
+        timestamp.get(0..4)?, timestamp.get(4..6)?, timestamp.get(6..8)?,
+        timestamp.get(9..11)?, timestamp.get(11..13)?, timestamp.get(13..15)?
+    ))?;
+    let formatted = format_timestamp(created).replace(['-', ':'], "").replace(' ', "-");
+    if formatted != timestamp {
+        return None;
+    }
+    match &run_id[15..] {
+        "" => Some(created),
+        suffix => suffix.strip_prefix('-')?.parse::<u32>().ok().map(|_| created),
+    }
+}
+
+fn read_manifest(directory: &Path) -> Vec<QuarantineEntry> {
+    let file = match File::open(directory.join(MANIFEST_FILE)) {
+        Ok(file) => file,
+        Err(_) => return vec![],
+    };
+    BufReader::new(file).lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+fn write_manifest(directory: &Path, entries: &[QuarantineEntry]) -> io::Result<()> {
+    let mut manifest = File::create(directory.join(MANIFEST_FILE))?;
+    for entry in entries {
+        let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+        writeln!(manifest, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn move_path(from: &Path, to: &Path) -> io::Result<()> {
+    match fs::rename(from, to) {
+        Ok(_) => Ok(()),
+        Err(error) if is_cross_device(&error) => {
+            if let Err(error) = copy_path(from, to) {
+                let _ = remove_path(to);
+                return Err(error);
+            }
+            if let Err(error) = remove_path(from) {
+                // a directory may already be partially removed, keep the copy in that case
+                if !to.is_dir() {
+                    let _ = remove_path(to);
+                }
+                return Err(error);
+            }
+            Ok(())
+        }
+        Err(error) => Err(error),
+    }
+}
+
+fn copy_path(from: &Path, to: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(from)?;
+    if metadata.file_type().is_symlink() {
+        copy_symlink(from, to)?;
+    }
+    else if metadata.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy_path(&entry.path(), &to.join(entry.file_name()))?;
+        }
+    }
+    else {
+        fs::copy(from, to)?;
+        if let Ok(modified) = metadata.modified() {
+            let _ = File::options().write(true).open(to).and_then(|file| file.set_modified(modified));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(from)?, to)
+}
+
+#[cfg(windows)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    let target = fs::read_link(from)?;
+    if from.is_dir() {
+        std::os::windows::fs::symlink_dir(target, to)
+    }
+    else {
+        std::os::windows::fs::symlink_file(target, to)
+    }
+}
+
+fn remove_path(path: &Path) -> io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        fs::remove_dir_all(path)
+    }
+    else {
+        fs::remove_file(path)
+    }
+}
+
+fn is_cross_device(error: &io::Error) -> bool {
+    // ERROR_NOT_SAME_DEVICE on Windows, EXDEV on unix
+    #[cfg(windows)]
+    return error.raw_os_error() == Some(17);
+    #[cfg(not(windows))]
+    return error.raw_os_error() == Some(18);
+}
+
+pub(crate) fn unix_now() -> u64 {
+    system_time_to_secs(SystemTime::now())
+}
+
+pub(crate) fn system_time_to_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0)
+}
@@ -0,0 +1,213 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::filesystem::convert_metadata;
+
+// Categories that hold credentials, offered for secure overwrite in the UI
+pub const SECURE_CATEGORIES: [&str; 3] = ["Browser passwords", "Browser cookies", "Accounts"];
+pub const DEFAULT_PASSES: u32 = 1;
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Default)]
+pub struct Overwritten {
+    pub written: u64,
+    // files with other hard links, their content is still reachable through those and is left alone
+    pub hard_linked: Vec<PathBuf>,
+}
+
+// Overwrites a file, or every file below a directory, `passes` times with pseudo-random data
+// and truncates it, so the caller can unlink it afterwards. Symlinks are never followed.
+pub fn overwrite_path(path: &Path, passes: u32) -> io::Result<Overwritten> {
+    let mut overwritten = Overwritten::default();
+    overwrite_into(path, passes, &mut overwritten)?;
+    Ok(overwritten)
+}
+
+fn overwrite_into(path: &Path, passes: u32, overwritten: &mut Overwritten) -> io::Result<()> {
+    let metadata = convert_metadata(&fs::symlink_metadata(path)?);
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path)? {
+            overwrite_into(&entry?.path(), passes, overwritten)?;
+        }
+        return Ok(());
+    }
+    if !metadata.is_file() {
+        return Ok(());
+    }
+    if metadata.links > 1 {
+        overwritten.hard_linked.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let length = metadata.len;
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    let mut state = seed();
+    for _ in 0..passes.max(1) {
+        file.seek(SeekFrom::Start(0))?;
+        let mut remaining = length;
+        while remaining > 0 {
+            fill(&mut buffer, &mut state);
+            let chunk = remaining.min(BUFFER_SIZE as u64) as usize;
+            file.write_all(&buffer[..chunk])?;
+            remaining -= chunk as u64;
+            overwritten.written += chunk as u64;
+        }
+        // every pass has to reach the disk, otherwise the next one only replaces it in the cache
+        file.sync_all()?;
+    }
+    file.set_len(0)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+fn seed() -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos() as u64).unwrap_or(0);
+    nanos | 1
+}
+
+// xorshift64, the data only has to differ from the original content
+fn fill(buffer: &mut [u8], state: &mut u64) {
+    for chunk in buffer.chunks_mut(8) {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        let bytes = state.to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::PathBuf;
+    use database::structures::{CleanerAction, CleanerData, CleanerSkipReason};
+    use super::*;
+    use crate::{clear_data, CleanerOptions};
+
+    // A fresh directory per test, removed again when the test ends
+    struct TempDirectory(PathBuf);
+
+    impl TempDirectory {
+        fn new(name: &str) -> TempDirectory {
+            let path = std::env::temp_dir().join(format!("winbooster-shred-{}-{}", std::process::id(), name));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            TempDirectory(path)
+        }
+    }
+
+    impl Drop for TempDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn every_pass_covers_the_whole_file_and_truncates_it() {
+        let directory = TempDirectory::new("passes");
+        let file = directory.0.join("secret.txt");
+        // more than one buffer, so the last chunk is a partial one
+        let length = BUFFER_SIZE as u64 * 2 + 100;
+        fs::write(&file, vec![b'a'; length as usize]).unwrap();
+
+        let overwritten = overwrite_path(&file, 3).unwrap();
+
+        assert_eq!(overwritten.written, length * 3);
+        assert!(overwritten.hard_linked.is_empty());
+        assert_eq!(fs::metadata(&file).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn zero_passes_still_overwrite_once() {
+        let directory = TempDirectory::new("zero-passes");
+        let file = directory.0.join("secret.txt");
+        fs::write(&file, b"password").unwrap();
+
+        assert_eq!(overwrite_path(&file, 0).unwrap().written, 8);
+        assert_eq!(fs::metadata(&file).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn directories_are_overwritten_recursively() {
+        let directory = TempDirectory::new("recursive");
+        fs::create_dir_all(directory.0.join("profile/cookies")).unwrap();
+        fs::write(directory.0.join("profile/logins.json"), b"0123456789").unwrap();
+        fs::write(directory.0.join("profile/cookies/session"), b"01234").unwrap();
+
+        let overwritten = overwrite_path(&directory.0.join("profile"), 1).unwrap();
+
+        assert_eq!(overwritten.written, 15);
+        assert_eq!(fs::metadata(directory.0.join("profile/logins.json")).unwrap().len(), 0);
+        assert_eq!(fs::metadata(directory.0.join("profile/cookies/session")).unwrap().len(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_targets_are_left_alone() {
+        let directory = TempDirectory::new("symlink");
+        let target = directory.0.join("target.txt");
+        fs::write(&target, b"keep me").unwrap();
+        std::os::unix::fs::symlink(&target, directory.0.join("link")).unwrap();
+
+        assert_eq!(overwrite_path(&directory.0.join("link"), 1).unwrap().written, 0);
+        assert_eq!(fs::read(&target).unwrap(), b"keep me");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hard_linked_files_are_reported_and_not_overwritten() {
+        let directory = TempDirectory::new("hard-link");
+        let file = directory.0.join("cookies.sqlite");
+        fs::write(&file, b"shared content").unwrap();
+        fs::hard_link(&file, directory.0.join("backup.sqlite")).unwrap();
+
+        let overwritten = overwrite_path(&file, 1).unwrap();
+
+        assert_eq!(overwritten.written, 0);
+        assert_eq!(overwritten.hard_linked, vec![file.clone()]);
+        assert_eq!(fs::read(directory.0.join("backup.sqlite")).unwrap(), b"shared content");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn secure_removal_overwrites_then_removes() {
+        let directory = TempDirectory::new("removal");
+        fs::write(directory.0.join("cookies"), b"0123456789").unwrap();
+        fs::write(directory.0.join("linked"), b"shared").unwrap();
+        // a second name outside the cleaned directory
+        let outside_link = std::env::temp_dir().join(format!("winbooster-shred-{}-removal-link", std::process::id()));
+        fs::hard_link(directory.0.join("linked"), &outside_link).unwrap();
+
+        let data = CleanerData {
+            path: directory.0.join("*").to_string_lossy().to_string(),
+            category: "Browser cookies".to_string(),
+            program: "Browser".to_string(),
+            actions: vec![CleanerAction::RemoveFile],
+            min_age_days: None,
+            exclude: vec![],
+            case_insensitive: false,
+            retention: None,
+            processes: vec![],
+        };
+        let options = CleanerOptions {
+            secure_overwrite: HashMap::from([("Browser cookies".to_string(), 2)]),
+            ..CleanerOptions::default()
+        };
+        let result = clear_data(&data, &options);
+        let outside_content = fs::read(&outside_link);
+        let _ = fs::remove_file(&outside_link);
+
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files, 2);
+        assert_eq!(result.overwritten_bytes, 20);
+        assert!(fs::read_dir(&directory.0).unwrap().next().is_none());
+        assert_eq!(result.skipped.len(), 1);
+        assert!(result.skipped[0].reason == CleanerSkipReason::HardLinked);
+        // unlinking one name left the content of the other one intact
+        assert_eq!(outside_content.unwrap(), b"shared");
+    }
+}
@@ -0,0 +1,194 @@
+use std::io;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::cancellation::CancellationToken;
+
+// Limits of background mode when the user does not pick their own
+pub const BACKGROUND_DELETIONS_PER_SECOND: u64 = 200;
+pub const BACKGROUND_SCANNED_BYTES_PER_SECOND: u64 = 32 * 1024 * 1024;
+
+// Longest sleep between two cancellation checks
+const WAIT_STEP: Duration = Duration::from_millis(100);
+
+// Shared by every worker of a run, so the limits hold for the whole run and not per rule
+pub struct Throttle {
+    deletions: Option<Rate>,
+    scanned_bytes: Option<Rate>,
+    low_priority: bool,
+}
+
+impl Throttle {
+    // None leaves that side unlimited, low_priority lowers the CPU and I/O priority of the threads running rules
+    pub fn new(deletions_per_second: Option<u64>, scanned_bytes_per_second: Option<u64>, low_priority: bool) -> Throttle {
+        Throttle {
+            deletions: deletions_per_second.filter(|rate| *rate > 0).map(Rate::new),
+            scanned_bytes: scanned_bytes_per_second.filter(|rate| *rate > 0).map(Rate::new),
+            low_priority,
+        }
+    }
+
+    pub fn background() -> Throttle {
+        Throttle::new(Some(BACKGROUND_DELETIONS_PER_SECOND), Some(BACKGROUND_SCANNED_BYTES_PER_SECOND), true)
+    }
+
+    // Lowers the calling thread until the guard is dropped, the rest of the process keeps its priority.
+    // Best effort, a rule at normal priority is still throttled
+    pub fn lower_priority(&self) -> Option<LoweredPriority> {
+        if !self.low_priority {
+            return None;
+        }
+        lower_thread_priority().ok().map(|saved| LoweredPriority { saved, thread: PhantomData })
+    }
+
+    pub fn wait_deletions(&self, count: u64, cancellation: &CancellationToken) {
+        if let Some(rate) = &self.deletions {
+            rate.acquire(count, cancellation);
+        }
+    }
+
+    pub fn wait_scanned(&self, bytes: u64, cancellation: &CancellationToken) {
+        if let Some(rate) = &self.scanned_bytes {
+            rate.acquire(bytes, cancellation);
+        }
+    }
+}
+
+// Paces a resource to `per_second` units, an operation reserves its share and waits for its turn.
+// Idle time is not saved up, so a pause is never followed by a burst.
+struct Rate {
+    per_second: u64,
+    next: Mutex<Instant>,
+}
+
+impl Rate {
+    fn new(per_second: u64) -> Rate {
+        Rate {
+            per_second,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    fn acquire(&self, amount: u64, cancellation: &CancellationToken) {
+        let start = {
+            let mut next = self.next.lock().unwrap();
+            let start = (*next).max(Instant::now());
+            *next = start + Duration::from_secs_f64(amount as f64 / self.per_second as f64);
+            start
+        };
+        loop {
+            let now = Instant::now();
+            if now >= start || cancellation.is_cancelled() {
+                return;
+            }
+            thread::sleep((start - now).min(WAIT_STEP));
+        }
+    }
+}
+
+// Restores the priority on drop, so it stays on the thread that lowered it
+pub struct LoweredPriority {
+    saved: SavedPriority,
+    thread: PhantomData<*const ()>,
+}
+
+impl Drop for LoweredPriority {
+    fn drop(&mut self) {
+        let _ = restore_thread_priority(&self.saved);
+    }
+}
+
+// The nice value and the I/O priority belong to single threads on Linux, 0 picks the calling thread
+#[cfg(target_os = "linux")]
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+
+#[cfg(target_os = "linux")]
+struct SavedPriority {
+    nice: libc::c_int,
+    ioprio: libc::c_int,
+}
+
+#[cfg(target_os = "linux")]
+fn lower_thread_priority() -> io::Result<SavedPriority> {
+    // best effort class at its lowest level
+    const IOPRIO_CLASS_BE: libc::c_int = 2;
+    const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+    const IOPRIO_LOWEST: libc::c_int = 7;
+    let ioprio = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, 0) };
+    if ioprio < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // -1 is a valid nice value as well, only errno tells it from a failure
+    unsafe { *libc::__errno_location() = 0 };
+    let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS, 0) };
+    if nice == -1 && io::Error::last_os_error().raw_os_error() != Some(0) {
+        return Err(io::Error::last_os_error());
+    }
+    let saved = SavedPriority { nice, ioprio: ioprio as libc::c_int };
+    if unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, 0, (IOPRIO_CLASS_BE << IOPRIO_CLASS_SHIFT) | IOPRIO_LOWEST) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, 19) } != 0 {
+        let error = io::Error::last_os_error();
+        let _ = restore_thread_priority(&saved);
+        return Err(error);
+    }
+    Ok(saved)
+}
+
+// Without CAP_SYS_NICE the nice value cannot be raised again, then the thread keeps it until it exits.
+// The run_pool workers exit with their run, so later runs start at normal priority
+#[cfg(target_os = "linux")]
+fn restore_thread_priority(saved: &SavedPriority) -> io::Result<()> {
+    if unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, 0, saved.ioprio) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, saved.nice) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+// Background mode lowers both the CPU and the I/O priority of the calling thread, _END restores both
+#[cfg(windows)]
+struct SavedPriority;
+
+#[cfg(windows)]
+fn set_thread_mode(mode: u32) -> io::Result<()> {
+    use std::ffi::c_void;
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetCurrentThread() -> *mut c_void;
+        fn SetThreadPriority(thread: *mut c_void, priority: i32) -> i32;
+    }
+    if unsafe { SetThreadPriority(GetCurrentThread(), mode as i32) } == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn lower_thread_priority() -> io::Result<SavedPriority> {
+    const THREAD_MODE_BACKGROUND_BEGIN: u32 = 0x00010000;
+    set_thread_mode(THREAD_MODE_BACKGROUND_BEGIN).map(|_| SavedPriority)
+}
+
+#[cfg(windows)]
+fn restore_thread_priority(_saved: &SavedPriority) -> io::Result<()> {
+    const THREAD_MODE_BACKGROUND_END: u32 = 0x00020000;
+    set_thread_mode(THREAD_MODE_BACKGROUND_END)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+struct SavedPriority;
+
+#[cfg(not(any(target_os = "linux", windows)))]
+fn lower_thread_priority() -> io::Result<SavedPriority> {
+    Ok(SavedPriority)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+fn restore_thread_priority(_saved: &SavedPriority) -> io::Result<()> {
+    Ok(())
+}
@@ -1,50 +1,124 @@
-use std::fmt::{Debug};
-use std::{env, fs};
+use std::collections::BTreeMap;
+use std::env;
 use std::io::stdin;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use crossterm::execute;
-use glob::{glob, Paths, PatternError};
 use inquire::formatter::MultiOptionFormatter;
 use inquire::list_option::ListOption;
-use inquire::MultiSelect;
+use inquire::{Confirm, MultiSelect, Select, Text};
 use inquire::validator::Validation;
 use tabled::{Table, Tabled};
 use tokio::task;
 use indicatif::{ProgressBar, ProgressStyle};
 use notify_rust::Notification;
-use cleaner::clear_data;
+use cleaner::{clear_plan, preview_plan, CleanerOptions};
+use cleaner::accounting::SpaceAccounting;
+use cleaner::cancellation::CancellationToken;
+use cleaner::exclusions::{self, compile_patterns, load_exclusions};
+use cleaner::free_space::{FreeSpaceMonitor, MountSpace};
+use cleaner::journal::{self, Journal, JournalEntry, JournalRun};
+use cleaner::observer::{CleanerEvent, CleanerEventKind, CleanerObserver};
+use cleaner::plan::{plan_retry, plan_rules, CleanerPlan};
+use cleaner::pool::{fair_order, run_pool, PoolEvent};
+use cleaner::processes::{find_running_programs, OpenFiles, RunningProgramPolicy};
+use cleaner::protection::load_protected_paths;
+use cleaner::shred::{DEFAULT_PASSES, SECURE_CATEGORIES};
+use cleaner::throttle::{Throttle, BACKGROUND_DELETIONS_PER_SECOND, BACKGROUND_SCANNED_BYTES_PER_SECOND};
+use cleaner::quarantine::{self, Quarantine, QUARANTINE_EXPIRY_DAYS};
+use database::cleaner_database::load_database;
+#[cfg(windows)]
 use database::registry_database;
-use database::structures::{CleanerData, CleanerResult, Cleared};
-use database::utils::get_file_size_string;
+use database::rule_files::{self, load_rule_directories};
+use database::structures::{CleanerData, CleanerError, CleanerLock, CleanerResult, CleanerSkip, Cleared, PreviewEntry};
+use database::utils::{format_timestamp, get_file_size_string};
 
-async fn work(disabledPrograms: Vec<&str>, categories: Vec<&str>, database: Vec<CleanerData>) {
+// The run Ctrl+C cancels. Without one, or when it is already cancelled, Ctrl+C exits.
+static ACTIVE_RUN: Mutex<Option<CancellationToken>> = Mutex::new(None);
+
+// Installed once, tokio keeps its SIGINT handler for the rest of the process
+fn handle_ctrl_c() {
+    task::spawn(async {
+        while tokio::signal::ctrl_c().await.is_ok() {
+            let active = ACTIVE_RUN.lock().unwrap().clone();
+            match active {
+                Some(cancellation) if !cancellation.is_cancelled() => cancellation.cancel(),
+                _ => std::process::exit(130),
+            }
+        }
+    });
+}
+
+// Makes Ctrl+C cancel `cancellation` until it is dropped
+struct ActiveRun;
+
+impl ActiveRun {
+    fn start(cancellation: &CancellationToken) -> ActiveRun {
+        *ACTIVE_RUN.lock().unwrap() = Some(cancellation.clone());
+        ActiveRun
+    }
+}
+
+impl Drop for ActiveRun {
+    fn drop(&mut self) {
+        *ACTIVE_RUN.lock().unwrap() = None;
+    }
+}
+
+// Shows the path being cleaned and the running total while a rule is still in progress
+struct ProgressObserver {
+    progress_bar: ProgressBar,
+    removed: AtomicU64,
+}
+
+impl CleanerObserver for ProgressObserver {
+    fn notify(&self, event: &CleanerEvent) {
+        match event.kind {
+            CleanerEventKind::Scanned => self.progress_bar.set_message(event.path.to_string()),
+            CleanerEventKind::Removed => {
+                let removed = self.removed.fetch_add(event.bytes, Ordering::SeqCst) + event.bytes;
+                self.progress_bar.set_prefix(format!("Clearing, removed {}", get_file_size_string(removed)));
+            }
+            CleanerEventKind::Waiting => self.progress_bar.set_message(format!("waiting for {} to close", event.path)),
+            _ => {}
+        }
+    }
+}
+
+async fn work(disabled_programs: Vec<&str>, categories: Vec<&str>, database: Vec<CleanerData>, mut options: CleanerOptions) {
     let sty = ProgressStyle::with_template(
         "[{elapsed_precise}] {prefix:.bold.dim} {spinner:.green}\n[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} [{msg}]",
     ).unwrap().progress_chars("##-").tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ");
 
 
-    let cat2 = categories.clone();
     let mut bytes_cleared = 0;
     let mut removed_files = 0;
     let mut removed_directories = 0;
     let mut cleared_programs:Vec<Cleared> = vec![];
+    let mut errors: Vec<CleanerError> = vec![];
+    let mut skipped: Vec<CleanerSkip> = vec![];
+    let mut locked: Vec<CleanerLock> = vec![];
+    let mut overwritten_bytes = 0;
 
     let pb = ProgressBar::new(0);
     pb.set_style(sty.clone());
     pb.set_prefix("Clearing");
-
-    let database2 = database.iter().to_owned();
-    let database3 = database.iter().to_owned();
-
-    let async_list: Vec<_> = database3
-        .filter(|data| categories.contains(&&*"LastActivity"))
-        .map(|data| {})
-        .collect();
+    options.observer = Some(Arc::new(ProgressObserver {
+        progress_bar: pb.clone(),
+        removed: AtomicU64::new(0),
+    }));
+    match Journal::create(&categories, &disabled_programs, options.quarantine.as_ref().map(|quarantine| quarantine.run_id())) {
+        Ok(journal) => options.journal = Some(Arc::new(journal)),
+        Err(error) => eprintln!("Failed to create the run journal: {}", error),
+    }
+    let accounting = Arc::new(SpaceAccounting::default());
+    options.accounting = Some(accounting.clone());
 
     let mut threads = vec![];
 
-    let mut has_last_activity = !async_list.is_empty();
+    let has_last_activity = !database.is_empty() && categories.contains(&"LastActivity");
 
     let clear_last_activity_task = {
         let progress_bar = Arc::new(pb.clone());
@@ -56,6 +130,10 @@ async fn work(disabledPrograms: Vec<&str>, categories: Vec<&str>, database: Vec<
                 working: false,
                 path: String::new(),
                 program: String::new(),
+                errors: vec![],
+                skipped: vec![],
+                overwritten_bytes: 0,
+                locked: vec![],
             };
 
             if has_last_activity {
@@ -71,68 +149,656 @@ async fn work(disabledPrograms: Vec<&str>, categories: Vec<&str>, database: Vec<
 
     threads.push(clear_last_activity_task);
 
-    let async_list: Vec<_> = database2
-        .filter(|data| categories.contains(&&*data.category) && !disabledPrograms.contains(&&*data.program))
-        .map(|data| {
-            let data = Arc::new(data.clone());
-            let progress_bar = Arc::new(pb.clone());
-            task::spawn(async move {
-                progress_bar.set_message(format!("{}", data.path));
-                let result = clear_data(&data);
-                progress_bar.inc(1);
-                result
-            })
-        })
-        .collect();
-    threads.extend(async_list);
+    let entries: Vec<CleanerData> = fair_order(database.iter()
+        .filter(|data| categories.contains(&&*data.category) && !disabled_programs.contains(&&*data.program))
+        .cloned()
+        .collect());
+    pb.set_length((threads.len() + entries.len()) as u64);
 
-    pb.set_length(threads.len() as u64);
+    // Ctrl+C stops the run and the retries between file operations, what was removed so far is still reported
+    let active_run = ActiveRun::start(&options.cancellation);
+    let clear_task = {
+        let progress_bar = pb.clone();
+        let mut options = options.clone();
+        task::spawn_blocking(move || {
+            let mut results = vec![];
+            progress_bar.set_message("planning");
+            let plans = plan_rules(&entries, &options);
+            options.open_files = Some(Arc::new(OpenFiles::snapshot()));
+            let free_space = FreeSpaceMonitor::sample(plans.iter().flat_map(|plan| &plan.paths).map(|path| Path::new(&path.path)));
+            run_pool(&plans, options.concurrency, |plan| clear_plan(plan, &options), |event| match event {
+                PoolEvent::Started(plan) => progress_bar.set_message(plan.data.path.clone()),
+                PoolEvent::Finished(_, result) => {
+                    progress_bar.inc(1);
+                    results.push(result);
+                }
+            });
+            (plans, results, free_space)
+        })
+    };
 
+    let mut results = vec![];
     for async_task in threads {
         match async_task.await {
-            Ok(result) => {
-                removed_files += result.files;
-                removed_directories += result.folders;
-                bytes_cleared += result.bytes;
-                if result.working {
-                    let data2 = Cleared { Program: result.program };
-                    if !cleared_programs.contains(&data2) {
-                        cleared_programs.push(data2);
-                    }
-                }
-            },
+            Ok(result) => results.push(result),
             Err(_) => {
                 eprintln!("Error waiting for task completion");
             }
         }
     }
+    let mut plans: Vec<CleanerPlan> = vec![];
+    let mut free_space = None;
+    match clear_task.await {
+        Ok((pool_plans, pool_results, pool_free_space)) => {
+            plans = pool_plans;
+            results.extend(pool_results);
+            free_space = Some(pool_free_space);
+        }
+        Err(_) => {
+            eprintln!("Error waiting for task completion");
+        }
+    }
+
+    for result in results {
+        removed_files += result.files;
+        removed_directories += result.folders;
+        bytes_cleared += result.bytes;
+        errors.extend(result.errors);
+        skipped.extend(result.skipped);
+        locked.extend(result.locked);
+        overwritten_bytes += result.overwritten_bytes;
+        if result.working {
+            let data2 = Cleared { program: result.program };
+            if !cleared_programs.contains(&data2) {
+                cleared_programs.push(data2);
+            }
+        }
+    }
+
+    let cancelled = options.cancellation.is_cancelled();
+    if cancelled {
+        pb.abandon_with_message("cancelled");
+        println!("Cancelled, partial summary:");
+    }
+    else {
+        pb.set_message("done");
+        pb.finish();
+    }
 
-    pb.set_message(format!("{}", "done"));
-    pb.finish();
+    while !locked.is_empty() && !cancelled {
+        println!("{} paths are held open by other programs:", locked.len());
+        println!("{}", Table::new(locked.clone()));
+        let ans_retry = Confirm::new("Retry the locked files? Close the programs holding them first")
+            .with_default(false)
+            .prompt();
+        if !matches!(ans_retry, Ok(true)) {
+            break;
+        }
+        let retry_plans = plan_retry(&plans, &locked);
+        let mut retry_options = options.clone();
+        let retry_results = task::spawn_blocking(move || {
+            let mut results = vec![];
+            // the holders may be gone or replaced by others by now
+            retry_options.open_files = Some(Arc::new(OpenFiles::snapshot()));
+            run_pool(&retry_plans, retry_options.concurrency, |plan| clear_plan(plan, &retry_options), |event| {
+                if let PoolEvent::Finished(_, result) = event {
+                    results.push(result);
+                }
+            });
+            results
+        }).await.unwrap_or_default();
+        locked.clear();
+        let mut retried_bytes = 0;
+        for result in retry_results {
+            removed_files += result.files;
+            removed_directories += result.folders;
+            bytes_cleared += result.bytes;
+            retried_bytes += result.bytes;
+            overwritten_bytes += result.overwritten_bytes;
+            locked.extend(result.locked);
+            errors.extend(result.errors);
+            if result.working {
+                let data2 = Cleared { program: result.program };
+                if !cleared_programs.contains(&data2) {
+                    cleared_programs.push(data2);
+                }
+            }
+        }
+        println!("Removed on retry: {}", get_file_size_string(retried_bytes));
+    }
+    drop(active_run);
 
     println!("Cleared programs:");
     let table = Table::new(cleared_programs).to_string();
     println!("{}", table);
     println!("Removed: {}", get_file_size_string(bytes_cleared));
+    let usage = accounting.usage();
+    println!("Apparent size: {}", get_file_size_string(usage.apparent));
+    println!("Allocated size: {}", get_file_size_string(usage.allocated));
+    println!("Space actually reclaimed: {}", get_file_size_string(usage.reclaimed));
     println!("Removed files: {}", removed_files);
     println!("Removed directories: {}", removed_directories);
+    // measured after the retries, a file still held open frees nothing
+    let mounts = free_space.map(FreeSpaceMonitor::finish).unwrap_or_default();
+    if !mounts.is_empty() {
+        println!("Free space:");
+        println!("{}", Table::new(mounts.iter().map(FreeSpaceRow::new)));
+    }
+    if overwritten_bytes > 0 {
+        println!("Securely overwritten: {}", get_file_size_string(overwritten_bytes));
+    }
+    let errors_count = errors.len();
+    if !errors.is_empty() {
+        println!("Failed: {}", errors.len());
+        println!("{}", Table::new(errors));
+    }
+    if !skipped.is_empty() {
+        println!("Skipped: {}", skipped.len());
+        println!("{}", Table::new(skipped));
+    }
+    if !locked.is_empty() {
+        println!("Locked: {}", locked.len());
+        println!("{}", Table::new(locked));
+    }
+    if let Some(quarantine) = &options.quarantine {
+        println!("Quarantine run: {} (restore with \"restore {}\")", quarantine.run_id(), quarantine.run_id());
+    }
+    if let Some(journal) = &options.journal {
+        let _ = journal.finish(removed_files, removed_directories, bytes_cleared, errors_count, cancelled);
+        println!("Journal: {} (inspect with \"history {}\")", journal.run_id(), journal.run_id());
+    }
 
     let _ = Notification::new()
         .summary("WinBooster CLI")
-        .body(&*("Removed: ".to_owned() + &*get_file_size_string(bytes_cleared) + "\nFiles: " + &*removed_files.to_string()))
+        .body(&("Removed: ".to_owned() + &*get_file_size_string(bytes_cleared) + "\nFiles: " + &*removed_files.to_string()))
         .icon("assets\\icon.png")
         .show();
 }
 
+#[derive(Tabled)]
+struct FreeSpaceRow {
+    #[tabled(rename = "Filesystem")]
+    mount: String,
+    #[tabled(rename = "Free before")]
+    before: String,
+    #[tabled(rename = "Free after")]
+    after: String,
+    #[tabled(rename = "Freed")]
+    freed: String,
+}
+
+impl FreeSpaceRow {
+    fn new(space: &MountSpace) -> FreeSpaceRow {
+        let format_free = |free: Option<u64>| match free {
+            Some(free) => get_file_size_string(free),
+            None => "unknown".to_string(),
+        };
+        let freed = match space.freed() {
+            Some(freed) if freed < 0 => format!("-{}", get_file_size_string(freed.unsigned_abs() as u64)),
+            Some(freed) => get_file_size_string(freed as u64),
+            None => "unknown".to_string(),
+        };
+        FreeSpaceRow {
+            mount: space.mount.display().to_string(),
+            before: format_free(space.before),
+            after: format_free(space.after),
+            freed,
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct PreviewRow {
+    #[tabled(rename = "Path")]
+    path: String,
+    #[tabled(rename = "Type")]
+    kind: String,
+    #[tabled(rename = "Size")]
+    size: String,
+}
+
+async fn preview(disabled_programs: Vec<&str>, categories: Vec<&str>, database: Vec<CleanerData>, options: CleanerOptions) {
+    let pb = ProgressBar::new(0);
+    pb.set_style(ProgressStyle::with_template(
+        "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} [{msg}]",
+    ).unwrap().progress_chars("##-"));
+
+    let rules: Vec<CleanerData> = fair_order(database.iter()
+        .filter(|data| categories.contains(&&*data.category) && !disabled_programs.contains(&&*data.program))
+        .cloned()
+        .collect());
+    pb.set_length(rules.len() as u64);
+
+    let cancellation = options.cancellation.clone();
+    let active_run = ActiveRun::start(&cancellation);
+    let preview_task = {
+        let progress_bar = pb.clone();
+        task::spawn_blocking(move || {
+            let mut groups: BTreeMap<(String, String), Vec<PreviewEntry>> = BTreeMap::new();
+            progress_bar.set_message("planning");
+            let plans = plan_rules(&rules, &options);
+            run_pool(&plans, options.concurrency, |plan| preview_plan(plan, &options), |event| match event {
+                PoolEvent::Started(plan) => progress_bar.set_message(plan.data.path.clone()),
+                PoolEvent::Finished(_, entries) => {
+                    progress_bar.inc(1);
+                    for entry in entries {
+                        groups.entry((entry.program.clone(), entry.category.clone())).or_default().push(entry);
+                    }
+                }
+            });
+            groups
+        })
+    };
+    let groups = match preview_task.await {
+        Ok(groups) => groups,
+        Err(_) => {
+            eprintln!("Error waiting for task completion");
+            BTreeMap::new()
+        }
+    };
+    drop(active_run);
+    pb.finish_and_clear();
+    if cancellation.is_cancelled() {
+        println!("Cancelled, partial preview:");
+    }
+
+    let mut total_bytes = 0;
+    let mut total_entries = 0;
+    for ((program, category), entries) in groups {
+        let group_bytes: u64 = entries.iter().map(|entry| entry.bytes).sum();
+        total_bytes += group_bytes;
+        total_entries += entries.len();
+
+        println!("{} / {} ({})", program, category, get_file_size_string(group_bytes));
+        let rows: Vec<PreviewRow> = entries.into_iter().map(|entry| PreviewRow {
+            path: entry.path,
+            kind: if entry.is_dir { "Directory".to_string() } else { "File".to_string() },
+            size: get_file_size_string(entry.bytes),
+        }).collect();
+        println!("{}", Table::new(rows));
+    }
+    println!("Would remove: {}", get_file_size_string(total_bytes));
+    println!("Would remove entries: {}", total_entries);
+}
+
+#[derive(Tabled)]
+struct QuarantineRunRow {
+    #[tabled(rename = "Run")]
+    run_id: String,
+    #[tabled(rename = "Created")]
+    created: String,
+    #[tabled(rename = "Entries")]
+    entries: usize,
+    #[tabled(rename = "Size")]
+    size: String,
+}
+
+fn restore(arguments: &[String]) {
+    let runs = quarantine::list_runs();
+    if runs.is_empty() {
+        println!("Quarantine is empty");
+        return;
+    }
+
+    let mut ids: Vec<u64> = vec![];
+    let run_id = if arguments.is_empty() {
+        let rows: Vec<QuarantineRunRow> = runs.iter().map(|run| QuarantineRunRow {
+            run_id: run.run_id.clone(),
+            created: format_timestamp(run.created),
+            entries: run.entries.len(),
+            size: get_file_size_string(run.entries.iter().map(|entry| entry.size).sum()),
+        }).collect();
+        println!("{}", Table::new(rows));
+
+        let run_ids: Vec<&str> = runs.iter().map(|run| &*run.run_id).collect();
+        let ans_run = match Select::new("Select the quarantine run:", run_ids).prompt() {
+            Ok(ans_run) => ans_run,
+            Err(_) => return,
+        };
+        let run = runs.iter().find(|run| run.run_id == ans_run).unwrap();
+        let entries: Vec<String> = run.entries.iter()
+            .map(|entry| format!("{} [{} / {}] ({})", entry.original_path, entry.program, entry.category, get_file_size_string(entry.size)))
+            .collect();
+        let ans_entries = MultiSelect::new("Select the entries to restore:", entries)
+            .with_all_selected_by_default()
+            .raw_prompt();
+        match ans_entries {
+            Ok(ans_entries) if !ans_entries.is_empty() => {
+                ids = ans_entries.iter().map(|option| run.entries[option.index].id).collect();
+            }
+            _ => return,
+        }
+        ans_run.to_string()
+    }
+    else {
+        for argument in &arguments[1..] {
+            match argument.parse::<u64>() {
+                Ok(id) => ids.push(id),
+                Err(_) => {
+                    eprintln!("Invalid entry id: {}", argument);
+                    return;
+                }
+            }
+        }
+        if !runs.iter().any(|run| run.run_id == arguments[0]) {
+            eprintln!("Quarantine run not found: {}", arguments[0]);
+            return;
+        }
+        arguments[0].clone()
+    };
+
+    let result = quarantine::restore(&run_id, &ids);
+    println!("Restored: {}", result.restored.len());
+    println!("Restored size: {}", get_file_size_string(result.restored.iter().map(|entry| entry.size).sum()));
+    if !result.errors.is_empty() {
+        println!("Failed: {}", result.errors.len());
+        println!("{}", Table::new(result.errors));
+    }
+}
+
+#[derive(Tabled)]
+struct JournalRunRow {
+    #[tabled(rename = "Run")]
+    run_id: String,
+    #[tabled(rename = "Started")]
+    started: String,
+    #[tabled(rename = "Categories")]
+    categories: String,
+    #[tabled(rename = "Removed")]
+    entries: usize,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+#[derive(Tabled)]
+struct JournalEntryRow {
+    #[tabled(rename = "Path")]
+    path: String,
+    #[tabled(rename = "Type")]
+    kind: String,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Modified")]
+    modified: String,
+    #[tabled(rename = "Program")]
+    program: String,
+    #[tabled(rename = "Category")]
+    category: String,
+    #[tabled(rename = "Rule")]
+    rule: String,
+}
+
+fn journal_entry_rows<'a>(entries: impl Iterator<Item = &'a JournalEntry>) -> Vec<JournalEntryRow> {
+    entries.map(|entry| JournalEntryRow {
+        path: entry.path.clone(),
+        kind: if entry.is_dir { "Directory".to_string() } else { "File".to_string() },
+        size: get_file_size_string(entry.size),
+        modified: if entry.modified > 0 { format_timestamp(entry.modified) } else { String::new() },
+        program: entry.program.clone(),
+        category: entry.category.clone(),
+        rule: entry.rule.clone(),
+    }).collect()
+}
+
+fn print_journal_run(run: &JournalRun) {
+    println!("Run: {}", run.run_id);
+    println!("Started: {}", format_timestamp(run.started));
+    println!("Categories: {}", run.categories.join(", "));
+    if !run.disabled_programs.is_empty() {
+        println!("Disabled programs: {}", run.disabled_programs.join(", "));
+    }
+    if let Some(quarantine_run) = &run.quarantine_run {
+        println!("Quarantine run: {} (restore with \"restore {}\")", quarantine_run, quarantine_run);
+    }
+    println!("Removed: {} ({})", run.entries.len(), get_file_size_string(run.entries.iter().map(|entry| entry.size).sum()));
+    println!("{}", Table::new(journal_entry_rows(run.entries.iter())));
+}
+
+fn history(arguments: &[String]) {
+    match arguments.first().map(|argument| &**argument) {
+        Some("find") if arguments.len() > 1 => {
+            // history find saves, matches paths, programs and categories
+            let text = arguments[1..].join(" ").to_lowercase();
+            for run in journal::list_runs() {
+                let found: Vec<&JournalEntry> = run.entries.iter()
+                    .filter(|entry| [&entry.path, &entry.program, &entry.category].iter().any(|field| field.to_lowercase().contains(&text)))
+                    .collect();
+                if !found.is_empty() {
+                    println!("Run {} ({}):", run.run_id, format_timestamp(run.started));
+                    println!("{}", Table::new(journal_entry_rows(found.into_iter())));
+                }
+            }
+        }
+        Some(run_id) => match journal::read_run(run_id) {
+            Some(run) => print_journal_run(&run),
+            None => eprintln!("Journal run not found: {}", run_id),
+        },
+        None => {
+            let runs = journal::list_runs();
+            if runs.is_empty() {
+                println!("No cleaning runs were recorded yet");
+                return;
+            }
+            let rows: Vec<JournalRunRow> = runs.iter().map(|run| JournalRunRow {
+                run_id: run.run_id.clone(),
+                started: format_timestamp(run.started),
+                categories: run.categories.join(", "),
+                entries: run.entries.len(),
+                size: get_file_size_string(run.entries.iter().map(|entry| entry.size).sum()),
+                status: match (run.finished, run.cancelled) {
+                    (None, _) => "interrupted".to_string(),
+                    (Some(_), true) => "cancelled".to_string(),
+                    (Some(_), false) => "finished".to_string(),
+                },
+            }).collect();
+            println!("{}", Table::new(rows));
+
+            // newest first, that is what people usually look for
+            let run_ids: Vec<&str> = runs.iter().rev().map(|run| &*run.run_id).collect();
+            if let Ok(ans_run) = Select::new("Select the run to inspect:", run_ids).prompt() {
+                if let Some(run) = runs.iter().find(|run| run.run_id == ans_run) {
+                    print_journal_run(run);
+                }
+            }
+        }
+    }
+}
+
+fn manage_exclusions(arguments: &[String]) {
+    match arguments.first().map(|argument| &**argument) {
+        Some("add") if arguments.len() > 1 => {
+            for pattern in &arguments[1..] {
+                match exclusions::add_exclusion(pattern) {
+                    Ok(_) => println!("Added exclusion: {}", pattern),
+                    Err(error) => eprintln!("Failed to add exclusion {}: {}", pattern, error),
+                }
+            }
+        }
+        Some("remove") if arguments.len() > 1 => {
+            for pattern in &arguments[1..] {
+                match exclusions::remove_exclusion(pattern) {
+                    Ok(true) => println!("Removed exclusion: {}", pattern),
+                    Ok(false) => eprintln!("Exclusion not found: {}", pattern),
+                    Err(error) => eprintln!("Failed to remove exclusion {}: {}", pattern, error),
+                }
+            }
+        }
+        Some("list") => {
+            for pattern in load_exclusions() {
+                println!("{}", pattern);
+            }
+        }
+        Some(_) => {
+            eprintln!("Usage: exclusions [list | add <pattern>... | remove <pattern>...]");
+        }
+        None => loop {
+            let patterns = load_exclusions();
+            println!("Exclusions ({}):", exclusions::get_exclusions_file().display());
+            for pattern in &patterns {
+                println!("  {}", pattern);
+            }
+
+            let ans_action = Select::new("Select the action:", vec!["Add", "Remove", "Done"]).prompt();
+            match ans_action {
+                Ok("Add") => {
+                    if let Ok(pattern) = Text::new("Exclusion pattern:").prompt() {
+                        if let Err(error) = exclusions::add_exclusion(pattern.trim()) {
+                            eprintln!("Failed to add exclusion: {}", error);
+                        }
+                    }
+                }
+                Ok("Remove") if !patterns.is_empty() => {
+                    if let Ok(selected) = MultiSelect::new("Select the exclusions to remove:", patterns).prompt() {
+                        for pattern in selected {
+                            let _ = exclusions::remove_exclusion(&pattern);
+                        }
+                    }
+                }
+                Ok("Remove") => {}
+                _ => break,
+            }
+        },
+    }
+}
+
+// The directories of every "--rules <directory>", they are needed before the other arguments are parsed
+fn get_rule_directory_arguments(arguments: &[String]) -> Vec<PathBuf> {
+    arguments.windows(2)
+        .filter(|pair| pair[0] == "--rules")
+        .map(|pair| PathBuf::from(&pair[1]))
+        .collect()
+}
+
+fn manage_rules(arguments: &[String]) {
+    match arguments.first().map(|argument| &**argument) {
+        Some("add") if arguments.len() > 1 => {
+            for directory in &arguments[1..] {
+                match rule_files::add_rule_directory(Path::new(directory)) {
+                    Ok(_) => println!("Added rule directory: {}", directory),
+                    Err(error) => eprintln!("Failed to add rule directory {}: {}", directory, error),
+                }
+            }
+        }
+        Some("remove") if arguments.len() > 1 => {
+            for directory in &arguments[1..] {
+                match rule_files::remove_rule_directory(Path::new(directory)) {
+                    Ok(true) => println!("Removed rule directory: {}", directory),
+                    Ok(false) => eprintln!("Rule directory not found: {}", directory),
+                    Err(error) => eprintln!("Failed to remove rule directory {}: {}", directory, error),
+                }
+            }
+        }
+        Some("list") | None => {
+            println!("User rules: {}", rule_files::get_user_rules_directory().display());
+            println!("Rule directories ({}):", rule_files::get_rule_directories_file().display());
+            for directory in load_rule_directories() {
+                println!("  {}", directory.display());
+            }
+        }
+        // loads everything like a run would and reports the broken files
+        Some("check") => {
+            let directories: Vec<PathBuf> = arguments[1..].iter().map(PathBuf::from).collect();
+            let loaded = load_database(&directories);
+            for error in &loaded.errors {
+                eprintln!("{}", error);
+            }
+            let mut programs: Vec<&str> = loaded.rules.iter().map(|data| &*data.program).collect();
+            programs.sort();
+            programs.dedup();
+            println!("Loaded {} rules of {} programs, {} files failed", loaded.rules.len(), programs.len(), loaded.errors.len());
+        }
+        Some(_) => {
+            eprintln!("Usage: rules [list | add <directory>... | remove <directory>... | check [directory...]]");
+        }
+    }
+}
+
+// Completes the options parsed from the command line with the user's lists and the quarantine
+fn create_options(base_options: &CleanerOptions, use_quarantine: bool) -> Option<CleanerOptions> {
+    let mut options = base_options.clone();
+    options.protected_paths = load_protected_paths();
+    match compile_patterns(&load_exclusions()) {
+        Ok(patterns) => options.exclusions = patterns,
+        Err(pattern) => {
+            eprintln!("Invalid exclusion pattern: {}", pattern);
+            return None;
+        }
+    }
+    if use_quarantine {
+        match Quarantine::create() {
+            Ok(quarantine) => options.quarantine = Some(Arc::new(quarantine)),
+            Err(error) => {
+                eprintln!("Failed to create quarantine: {}", error);
+                return None;
+            }
+        }
+        if options.prune_empty_directories {
+            println!("Empty directories are kept in quarantine mode, restoring could not bring them back");
+        }
+    }
+    Some(options)
+}
+
+fn parse_running_policy(value: &str) -> Option<RunningProgramPolicy> {
+    match value {
+        "skip" => Some(RunningProgramPolicy::Skip),
+        "wait" => Some(RunningProgramPolicy::Wait),
+        "ignore" => Some(RunningProgramPolicy::Ignore),
+        _ => None,
+    }
+}
+
+fn parse_secure_category(value: &str) -> Option<(String, u32)> {
+    match value.rsplit_once(':') {
+        Some((category, passes)) => match passes.parse::<u32>() {
+            Ok(passes) if passes > 0 => Some((category.to_string(), passes)),
+            _ => None,
+        },
+        None => Some((value.to_string(), DEFAULT_PASSES)),
+    }
+}
+
 #[tokio::main]
 async fn main() {
-    execute!(
+    handle_ctrl_c();
+    let _ = execute!(
         std::io::stdout(),
         crossterm::terminal::SetTitle("WinBooster Definitive Edition CLI v".to_owned() + &*database::get_winbooster_version())
     );
 
+    quarantine::expire_runs(Duration::from_secs(QUARANTINE_EXPIRY_DAYS * 24 * 60 * 60));
+
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    match arguments.first().map(|argument| &**argument) {
+        Some("restore") => {
+            restore(&arguments[1..]);
+            let mut s= String::new();
+            let _ = stdin().read_line(&mut s);
+            return;
+        }
+        Some("exclusions") => {
+            manage_exclusions(&arguments[1..]);
+            return;
+        }
+        Some("history") => {
+            history(&arguments[1..]);
+            return;
+        }
+        Some("rules") => {
+            manage_rules(&arguments[1..]);
+            return;
+        }
+        _ => {}
+    }
 
-    let database: Vec<CleanerData> = database::cleaner_database::get_database();
+    let rule_directories = get_rule_directory_arguments(&arguments);
+    let loaded = load_database(&rule_directories);
+    for error in &loaded.errors {
+        eprintln!("Failed to load rules from {}", error);
+    }
+    let database: Vec<CleanerData> = loaded.rules;
 
     let mut options: Vec<&str> = vec![];
     let mut programs: Vec<&str> = vec![];
@@ -146,9 +812,9 @@ async fn main() {
         }
 
     }
-    println!("DataBase Programs: {}", programs.iter().count());
+    println!("DataBase Programs: {}", programs.len());
     let validator = |a: &[ListOption<&&str>]| {
-        return if a.len() < 1 {
+        if a.is_empty() {
             Ok(Validation::Invalid("No category is selected!".into()))
         } else {
             Ok(Validation::Valid)
@@ -156,11 +822,106 @@ async fn main() {
     };
 
     let mut ans = vec![];
-    for argument in env::args() {
+    let mut preview_only = false;
+    let mut use_quarantine = false;
+    let mut running_policy_set = false;
+    let mut background = false;
+    let mut max_deletions = None;
+    let mut max_scan = None;
+    let mut base_options = CleanerOptions::default();
+    let mut arguments = env::args().skip(1);
+    while let Some(argument) = arguments.next() {
+        if argument == "--preview" {
+            preview_only = true;
+        }
+        if argument == "--quarantine" {
+            use_quarantine = true;
+        }
+        if argument == "--follow-symlinks" {
+            base_options.follow_symlinks = true;
+        }
+        if argument == "--prune-empty" {
+            base_options.prune_empty_directories = true;
+        }
+        if argument == "--min-age" {
+            match arguments.next().map(|days| days.parse::<u64>()) {
+                Some(Ok(days)) => base_options.min_age_days = Some(days),
+                _ => {
+                    eprintln!("--min-age expects a number of days");
+                    return;
+                }
+            }
+        }
+        if argument == "--jobs" {
+            match arguments.next().map(|jobs| jobs.parse::<usize>()) {
+                Some(Ok(jobs)) if jobs > 0 => base_options.concurrency = jobs,
+                _ => {
+                    eprintln!("--jobs expects a number of workers");
+                    return;
+                }
+            }
+        }
+        if argument == "--secure" {
+            // --secure "Browser cookies" or --secure "Browser cookies:3"
+            match arguments.next().map(|value| parse_secure_category(&value)) {
+                Some(Some((category, passes))) => {
+                    base_options.secure_overwrite.insert(category, passes);
+                }
+                _ => {
+                    eprintln!("--secure expects a category, optionally followed by :passes");
+                    return;
+                }
+            }
+        }
+        if argument == "--background" {
+            background = true;
+        }
+        if argument == "--max-deletions" {
+            match arguments.next().map(|rate| rate.parse::<u64>()) {
+                Some(Ok(rate)) if rate > 0 => max_deletions = Some(rate),
+                _ => {
+                    eprintln!("--max-deletions expects a number of deletions per second");
+                    return;
+                }
+            }
+        }
+        if argument == "--max-scan" {
+            match arguments.next().map(|rate| rate.parse::<u64>()) {
+                Some(Ok(rate)) if rate > 0 => max_scan = Some(rate * 1024 * 1024),
+                _ => {
+                    eprintln!("--max-scan expects a number of megabytes per second");
+                    return;
+                }
+            }
+        }
+        if argument == "--rules" {
+            // already loaded, see get_rule_directory_arguments
+            arguments.next();
+        }
+        if argument == "--running" {
+            match arguments.next().and_then(|value| parse_running_policy(&value)) {
+                Some(policy) => {
+                    base_options.running_programs = policy;
+                    running_policy_set = true;
+                }
+                None => {
+                    eprintln!("--running expects skip, wait or ignore");
+                    return;
+                }
+            }
+        }
         if options.contains(&&*argument) {
             ans.push(argument);
         }
     }
+    // --background picks the default limits, --max-deletions and --max-scan override them
+    if background {
+        max_deletions = max_deletions.or(Some(BACKGROUND_DELETIONS_PER_SECOND));
+        max_scan = max_scan.or(Some(BACKGROUND_SCANNED_BYTES_PER_SECOND));
+    }
+    if max_deletions.is_some() || max_scan.is_some() {
+        base_options.throttle = Some(Arc::new(Throttle::new(max_deletions, max_scan, background)));
+    }
 
     if ans.is_empty() {
         let formatter_categories: MultiOptionFormatter<'_, &str> = &|a| format!("{} selected categories", a.len());
@@ -183,13 +944,66 @@ async fn main() {
                 .prompt();
 
             if let Ok(ans_programs) = ans_programs {
-                work(ans_programs, ans_categories, database.clone()).await;
+                let ans_action = Select::new("Select the action:", vec!["Clear", "Move to quarantine", "Preview"]).prompt();
+                match ans_action {
+                    Ok("Preview") => {
+                        if let Some(options) = create_options(&base_options, false) {
+                            preview(ans_programs, ans_categories, database.clone(), options).await;
+                        }
+                    }
+                    Ok(action) => {
+                        let secure_categories: Vec<&str> = ans_categories.iter()
+                            .copied()
+                            .filter(|category| SECURE_CATEGORIES.contains(category))
+                            .collect();
+                        if action == "Clear" && base_options.secure_overwrite.is_empty() && !secure_categories.is_empty() {
+                            let ans_secure = Confirm::new(&format!("Securely overwrite files of {} before removing?", secure_categories.join(", ")))
+                                .with_default(false)
+                                .prompt();
+                            if let Ok(true) = ans_secure {
+                                for category in secure_categories {
+                                    base_options.secure_overwrite.insert(category.to_string(), DEFAULT_PASSES);
+                                }
+                            }
+                        }
+                        if !running_policy_set {
+                            let selected: Vec<CleanerData> = database.iter()
+                                .filter(|data| ans_categories.contains(&&*data.category) && !ans_programs.contains(&&*data.program))
+                                .cloned()
+                                .collect();
+                            let running = find_running_programs(&selected);
+                            if !running.is_empty() {
+                                println!("Running programs:");
+                                for program in &running {
+                                    println!("  {}", program.describe());
+                                }
+                                let ans_running = Select::new("Their files may be in use:", vec!["Skip them", "Wait until they close", "Clean anyway"]).prompt();
+                                base_options.running_programs = match ans_running {
+                                    Ok("Wait until they close") => RunningProgramPolicy::Wait,
+                                    Ok("Clean anyway") => RunningProgramPolicy::Ignore,
+                                    _ => RunningProgramPolicy::Skip,
+                                };
+                            }
+                        }
+                        if let Some(options) = create_options(&base_options, action == "Move to quarantine") {
+                            work(ans_programs, ans_categories, database.clone(), options).await;
+                        }
+                    }
+                    Err(_) => {}
+                }
             }
         }
     }
     else {
         let v2: Vec<&str> = ans.iter().map(|s| &**s).collect();
-        work(vec![], v2, database.clone()).await;
+        if preview_only {
+            if let Some(options) = create_options(&base_options, false) {
+                preview(vec![], v2, database.clone(), options).await;
+            }
+        }
+        else if let Some(options) = create_options(&base_options, use_quarantine) {
+            work(vec![], v2, database.clone(), options).await;
+        }
     }
 
     let mut s= String::new();
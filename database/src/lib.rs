@@ -4,6 +4,7 @@ pub mod structures;
 pub mod cleaner_database;
 pub mod registry_database;
 pub mod utils;
+pub mod rule_files;
 mod registry_utils;
 
 pub fn get_winbooster_version() -> String {
@@ -0,0 +1,325 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use crate::structures::{CleanerAction, CleanerData, CleanerRetention};
+use crate::utils::get_winbooster_directory;
+
+// One file of rules, see rules/schema.json
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    #[serde(default)]
+    rule: Vec<RuleEntry>,
+    #[serde(default)]
+    program: Vec<ProgramEntry>,
+}
+
+// How a program of the file is recognized among the running processes
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProgramEntry {
+    name: String,
+    processes: Vec<String>,
+}
+
+// CleanerData as it is written in a rule file, everything but program, category and path may be left out
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleEntry {
+    program: String,
+    category: String,
+    path: String,
+    #[serde(default)]
+    files_to_remove: Vec<String>,
+    #[serde(default)]
+    directories_to_remove: Vec<String>,
+    #[serde(default)]
+    actions: Vec<ActionEntry>,
+    min_age_days: Option<u64>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    // None follows the platform, paths are case-insensitive on Windows only
+    case_insensitive: Option<bool>,
+    retention: Option<RetentionEntry>,
+}
+
+// What happens to the matched path itself, the named entries have their own lists
+#[derive(Deserialize)]
+enum ActionEntry {
+    #[serde(rename = "remove_file")]
+    File,
+    #[serde(rename = "remove_directory")]
+    Directory,
+    #[serde(rename = "remove_directory_contents")]
+    DirectoryContents,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+enum RetentionEntry {
+    KeepNewest(usize),
+    KeepNewestBytes(u64),
+}
+
+pub struct RuleFileError {
+    pub file: String,
+    pub message: String,
+}
+
+impl Display for RuleFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.file, self.message)
+    }
+}
+
+// Values of the placeholders rule paths may use. A placeholder without values, such as {steam}
+// when Steam is not installed, leaves the rules using it out.
+pub struct Placeholders {
+    values: Vec<(&'static str, Vec<String>)>,
+}
+
+impl Placeholders {
+    pub fn current() -> Placeholders {
+        let mut values = vec![("username", vec![whoami::username()])];
+        #[cfg(windows)]
+        {
+            values.push(("drive", disk_name::get_letters()));
+            let steam = crate::registry_utils::get_steam_directory_from_registry();
+            values.push(("steam", if steam.is_empty() { vec![] } else { vec![steam] }));
+        }
+        #[cfg(not(windows))]
+        {
+            values.push(("drive", vec![]));
+            values.push(("steam", vec![]));
+        }
+        Placeholders { values }
+    }
+
+    // Every combination of the placeholder values, "{drive}Temp\*" gives one pattern per drive.
+    // Braces around anything but a lowercase name, like the GUIDs in Windows paths, are kept as they are.
+    pub fn expand(&self, template: &str) -> Result<Vec<String>, String> {
+        let mut expanded = vec![template.to_string()];
+        for name in find_placeholders(template) {
+            let values = match self.values.iter().find(|(known, _)| *known == name) {
+                Some((_, values)) => values,
+                None => return Err(format!("unknown placeholder {{{}}}", name)),
+            };
+            let placeholder = format!("{{{}}}", name);
+            expanded = expanded.iter()
+                .flat_map(|text| values.iter().map(|value| text.replace(&placeholder, value)))
+                .collect();
+        }
+        Ok(expanded)
+    }
+}
+
+fn find_placeholders(template: &str) -> Vec<&str> {
+    let mut names = vec![];
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        let end = match rest.find('}') {
+            Some(end) => end,
+            None => break,
+        };
+        let name = &rest[..end];
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    names
+}
+
+// Parses a rule file, TOML unless `name` ends with ".json"
+pub fn parse_rules(name: &str, content: &str, placeholders: &Placeholders) -> Result<Vec<CleanerData>, RuleFileError> {
+    let error = |message: String| RuleFileError { file: name.to_string(), message };
+    let file: RuleFile = if name.ends_with(".json") {
+        serde_json::from_str(content).map_err(|e| error(e.to_string()))?
+    }
+    else {
+        toml::from_str(content).map_err(|e| error(e.to_string()))?
+    };
+
+    let mut programs: Vec<(String, Vec<String>)> = vec![];
+    for program in &file.program {
+        let program_error = |message: &str| error(format!("program {}: {}", program.name, message));
+        if program.name.is_empty() || program.processes.is_empty() || program.processes.iter().any(String::is_empty) {
+            return Err(program_error("name and processes must not be empty"));
+        }
+        if programs.iter().any(|(name, _)| *name == program.name) {
+            return Err(program_error("is listed twice"));
+        }
+        if !file.rule.iter().any(|entry| entry.program == program.name) {
+            return Err(program_error("has no rules in this file"));
+        }
+        programs.push((program.name.clone(), program.processes.iter().map(|process| normalize_process(process)).collect()));
+    }
+
+    let mut rules = vec![];
+    for (index, entry) in file.rule.into_iter().enumerate() {
+        let rule_error = |message: String| error(format!("rule {} ({}): {}", index + 1, entry.path, message));
+        if entry.program.is_empty() || entry.category.is_empty() || entry.path.is_empty() {
+            return Err(rule_error("program, category and path must not be empty".to_string()));
+        }
+        let paths = placeholders.expand(&entry.path).map_err(&rule_error)?;
+        let mut exclude = vec![];
+        for pattern in &entry.exclude {
+            exclude.extend(placeholders.expand(pattern).map_err(&rule_error)?);
+        }
+        let mut actions = vec![];
+        if !entry.files_to_remove.is_empty() {
+            actions.push(CleanerAction::RemoveNamedFiles(entry.files_to_remove.clone()));
+        }
+        if !entry.directories_to_remove.is_empty() {
+            actions.push(CleanerAction::RemoveNamedDirectories(entry.directories_to_remove.clone()));
+        }
+        actions.extend(entry.actions.iter().map(|action| match action {
+            ActionEntry::File => CleanerAction::RemoveFile,
+            ActionEntry::Directory => CleanerAction::RemoveDirectory,
+            ActionEntry::DirectoryContents => CleanerAction::RemoveDirectoryContents,
+        }));
+        CleanerAction::validate(&actions).map_err(&rule_error)?;
+        let retention = entry.retention.as_ref().map(|retention| match retention {
+            RetentionEntry::KeepNewest(count) => CleanerRetention::KeepNewest(*count),
+            RetentionEntry::KeepNewestBytes(bytes) => CleanerRetention::KeepNewestBytes(*bytes),
+        });
+        let processes = programs.iter()
+            .find(|(name, _)| *name == entry.program)
+            .map(|(_, processes)| processes.clone())
+            .unwrap_or_default();
+        for path in paths {
+            rules.push(CleanerData {
+                path,
+                category: entry.category.clone(),
+                program: entry.program.clone(),
+                actions: actions.clone(),
+                min_age_days: entry.min_age_days,
+                exclude: exclude.clone(),
+                case_insensitive: entry.case_insensitive.unwrap_or(cfg!(windows)),
+                retention,
+                processes: processes.clone(),
+            });
+        }
+    }
+    Ok(rules)
+}
+
+// The names are compared with the lowercase executable names of the running processes
+fn normalize_process(process: &str) -> String {
+    let process = process.to_lowercase();
+    process.strip_suffix(".exe").map(str::to_string).unwrap_or(process)
+}
+
+// A program's processes may come from another file than some of its rules, every rule of the
+// program gets all of them
+pub fn share_processes(rules: &mut [CleanerData]) {
+    let mut programs: Vec<(String, Vec<String>)> = vec![];
+    for data in rules.iter() {
+        let index = match programs.iter().position(|(name, _)| *name == data.program) {
+            Some(index) => index,
+            None => {
+                programs.push((data.program.clone(), vec![]));
+                programs.len() - 1
+            }
+        };
+        for process in &data.processes {
+            if !programs[index].1.contains(process) {
+                programs[index].1.push(process.clone());
+            }
+        }
+    }
+    for data in rules.iter_mut() {
+        if let Some((_, processes)) = programs.iter().find(|(name, _)| *name == data.program) {
+            data.processes = processes.clone();
+        }
+    }
+}
+
+// Every .toml and .json file of the directory in name order, a broken file is reported and skipped
+pub fn load_rule_directory(directory: &Path, placeholders: &Placeholders, rules: &mut Vec<CleanerData>, errors: &mut Vec<RuleFileError>) {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) => {
+            errors.push(RuleFileError { file: directory.display().to_string(), message: error.to_string() });
+            return;
+        }
+    };
+    let mut files: Vec<PathBuf> = entries.flatten()
+        .map(|entry| entry.path())
+        .filter(|path| matches!(path.extension().and_then(|extension| extension.to_str()), Some("toml") | Some("json")))
+        .collect();
+    files.sort();
+    for file in files {
+        let name = file.display().to_string();
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(error) => {
+                errors.push(RuleFileError { file: name, message: error.to_string() });
+                continue;
+            }
+        };
+        match parse_rules(&name, &content, placeholders) {
+            Ok(file_rules) => rules.extend(file_rules),
+            Err(error) => errors.push(error),
+        }
+    }
+}
+
+// Always loaded after the built-in rules, it does not have to exist
+pub fn get_user_rules_directory() -> PathBuf {
+    get_winbooster_directory().join("rules")
+}
+
+// Further rule directories, one per line
+pub fn get_rule_directories_file() -> PathBuf {
+    get_winbooster_directory().join("rule_directories.txt")
+}
+
+pub fn load_rule_directories() -> Vec<PathBuf> {
+    match fs::read_to_string(get_rule_directories_file()) {
+        Ok(content) => content.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(PathBuf::from)
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+pub fn save_rule_directories(directories: &[PathBuf]) -> io::Result<()> {
+    let file = get_rule_directories_file();
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let lines: Vec<String> = directories.iter().map(|directory| directory.display().to_string()).collect();
+    fs::write(file, lines.join("\n") + "\n")
+}
+
+// Stored as an absolute path, so it does not depend on where WinBooster is started
+pub fn add_rule_directory(directory: &Path) -> io::Result<()> {
+    if !directory.is_dir() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "not a directory"));
+    }
+    let directory = std::path::absolute(directory)?;
+    let mut directories = load_rule_directories();
+    if !directories.contains(&directory) {
+        directories.push(directory);
+    }
+    save_rule_directories(&directories)
+}
+
+pub fn remove_rule_directory(directory: &Path) -> io::Result<bool> {
+    let mut directories = load_rule_directories();
+    let count = directories.len();
+    directories.retain(|known| known != directory);
+    if directories.len() == count {
+        return Ok(false);
+    }
+    save_rule_directories(&directories)?;
+    Ok(true)
+}
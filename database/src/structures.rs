@@ -1,13 +1,16 @@
+use std::fmt::{Display, Formatter};
+use std::io;
 use tabled::Tabled;
 
 #[derive(PartialEq, Tabled)]
 pub struct Cleared {
-    pub Program: String,
+    #[tabled(rename = "Program")]
+    pub program: String,
 }
 impl PartialEq<Option<Cleared>> for &Cleared {
     fn eq(&self, other: &Option<Cleared>) -> bool {
         match other {
-            Some(other) => other.Program.eq(&*self.Program),
+            Some(other) => other.program.eq(&*self.program),
             None => false,
         }
     }
@@ -18,14 +21,97 @@ pub struct CleanerData {
     pub category: String,
     pub program: String,
 
-    pub files_to_remove: Vec<String>,
-    pub folders_to_remove: Vec<String>,
-    pub directories_to_remove: Vec<String>,
+    // what happens to every path the glob matches, in this order, see CleanerAction::validate
+    pub actions: Vec<CleanerAction>,
+
+    pub min_age_days: Option<u64>,
+    pub exclude: Vec<String>,
+    // match path and the named entries ignoring case, the rule was written for Windows
+    pub case_insensitive: bool,
+    // keep the newest matches of the rule instead of removing all of them
+    pub retention: Option<CleanerRetention>,
+    // process names of the program, lowercase and without ".exe". Empty never counts as running
+    pub processes: Vec<String>,
+}
+#[derive(Clone, Copy, PartialEq)]
+pub enum CleanerRetention {
+    // the newest N paths by modification time
+    KeepNewest(usize),
+    // the newest paths as long as they fit into this many bytes together
+    KeepNewestBytes(u64),
+}
+// What a rule does with every path its glob matches
+#[derive(Clone, PartialEq)]
+pub enum CleanerAction {
+    // files with these names inside every matched directory
+    RemoveNamedFiles(Vec<String>),
+    // directories with these names inside every matched directory
+    RemoveNamedDirectories(Vec<String>),
+    // the matched path when it is a file
+    RemoveFile,
+    // the matched path with everything inside it when it is a directory
+    RemoveDirectory,
+    // everything inside the matched directory, the directory itself is kept
+    RemoveDirectoryContents,
+}
+impl Display for CleanerAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanerAction::RemoveNamedFiles(_) => write!(f, "files_to_remove"),
+            CleanerAction::RemoveNamedDirectories(_) => write!(f, "directories_to_remove"),
+            CleanerAction::RemoveFile => write!(f, "remove_file"),
+            CleanerAction::RemoveDirectory => write!(f, "remove_directory"),
+            CleanerAction::RemoveDirectoryContents => write!(f, "remove_directory_contents"),
+        }
+    }
+}
+impl CleanerAction {
+    // Every action at most once, a directory is either removed or emptied, and a name is either a file or a directory
+    pub fn validate(actions: &[CleanerAction]) -> Result<(), String> {
+        if actions.is_empty() {
+            return Err("the rule does not remove anything".to_string());
+        }
+        for (index, action) in actions.iter().enumerate() {
+            if actions[..index].iter().any(|previous| std::mem::discriminant(previous) == std::mem::discriminant(action)) {
+                return Err(format!("{} is listed twice", action));
+            }
+            match action {
+                CleanerAction::RemoveNamedFiles(names) | CleanerAction::RemoveNamedDirectories(names) if names.is_empty() => {
+                    return Err(format!("{} lists no names", action));
+                }
+                CleanerAction::RemoveNamedFiles(names) | CleanerAction::RemoveNamedDirectories(names) if names.iter().any(String::is_empty) => {
+                    return Err(format!("{} lists an empty name", action));
+                }
+                _ => {}
+            }
+        }
+        if actions.contains(&CleanerAction::RemoveDirectory) && actions.contains(&CleanerAction::RemoveDirectoryContents) {
+            return Err("remove_directory and remove_directory_contents contradict each other".to_string());
+        }
 
-    pub remove_all_in_dir: bool,
-    pub remove_directory_after_clean: bool,
-    pub remove_directories: bool,
-    pub remove_files: bool
+        let files = actions.iter().find_map(|action| match action {
+            CleanerAction::RemoveNamedFiles(files) => Some(files),
+            _ => None,
+        });
+        let directories = actions.iter().find_map(|action| match action {
+            CleanerAction::RemoveNamedDirectories(directories) => Some(directories),
+            _ => None,
+        });
+        if let (Some(files), Some(directories)) = (files, directories)
+            && let Some(name) = files.iter().find(|name| directories.contains(name)) {
+            return Err(format!("\"{}\" is listed both as a file and as a directory", name));
+        }
+        Ok(())
+    }
+}
+impl CleanerData {
+    // Names the rule appends to every matched path
+    pub fn named_entries(&self) -> impl Iterator<Item = &String> {
+        self.actions.iter().flat_map(|action| match action {
+            CleanerAction::RemoveNamedFiles(names) | CleanerAction::RemoveNamedDirectories(names) => names.as_slice(),
+            _ => &[],
+        })
+    }
 }
 pub struct CleanerResult {
     pub files: u64,
@@ -34,4 +120,128 @@ pub struct CleanerResult {
     pub working: bool,
     pub path: String,
     pub program: String,
+    pub errors: Vec<CleanerError>,
+    pub skipped: Vec<CleanerSkip>,
+    // extra bytes written by secure overwrite
+    pub overwritten_bytes: u64,
+    // paths left in place because another process holds them open
+    pub locked: Vec<CleanerLock>,
+}
+#[derive(Clone, Copy, PartialEq)]
+pub enum CleanerOperation {
+    Glob,
+    RemoveFile,
+    RemoveDirectory,
+    Quarantine,
+    Restore,
+    Overwrite,
+}
+impl Display for CleanerOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanerOperation::Glob => write!(f, "glob"),
+            CleanerOperation::RemoveFile => write!(f, "remove file"),
+            CleanerOperation::RemoveDirectory => write!(f, "remove directory"),
+            CleanerOperation::Quarantine => write!(f, "quarantine"),
+            CleanerOperation::Restore => write!(f, "restore"),
+            CleanerOperation::Overwrite => write!(f, "overwrite"),
+        }
+    }
+}
+#[derive(Clone, Copy, PartialEq)]
+pub enum CleanerErrorKind {
+    PermissionDenied,
+    NotFound,
+    InUse,
+    InvalidPattern,
+    InvalidRule,
+    // the path is not valid UTF-8, rules can not work on it
+    InvalidPath,
+    Protected,
+    // the option can not be used together with a custom FileSystem
+    Unsupported,
+    Other,
+}
+impl CleanerErrorKind {
+    pub fn from_io_error(error: &io::Error) -> CleanerErrorKind {
+        // ERROR_SHARING_VIOLATION and ERROR_LOCK_VIOLATION on Windows, EBUSY and ETXTBSY on unix
+        #[cfg(windows)]
+        let in_use = matches!(error.raw_os_error(), Some(32) | Some(33));
+        #[cfg(not(windows))]
+        let in_use = matches!(error.raw_os_error(), Some(16) | Some(26));
+
+        if in_use {
+            return CleanerErrorKind::InUse;
+        }
+        match error.kind() {
+            io::ErrorKind::PermissionDenied => CleanerErrorKind::PermissionDenied,
+            io::ErrorKind::NotFound => CleanerErrorKind::NotFound,
+            _ => CleanerErrorKind::Other,
+        }
+    }
+}
+impl Display for CleanerErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanerErrorKind::PermissionDenied => write!(f, "permission denied"),
+            CleanerErrorKind::NotFound => write!(f, "not found"),
+            CleanerErrorKind::InUse => write!(f, "in use"),
+            CleanerErrorKind::InvalidPattern => write!(f, "invalid pattern"),
+            CleanerErrorKind::InvalidRule => write!(f, "invalid rule"),
+            CleanerErrorKind::InvalidPath => write!(f, "path is not valid UTF-8"),
+            CleanerErrorKind::Protected => write!(f, "protected path, rule aborted"),
+            CleanerErrorKind::Unsupported => write!(f, "not supported on this filesystem"),
+            CleanerErrorKind::Other => write!(f, "other"),
+        }
+    }
+}
+#[derive(Clone, Tabled)]
+pub struct CleanerError {
+    #[tabled(rename = "Path")]
+    pub path: String,
+    #[tabled(rename = "Operation")]
+    pub operation: CleanerOperation,
+    #[tabled(rename = "Error")]
+    pub kind: CleanerErrorKind,
+}
+#[derive(Clone)]
+pub struct PreviewEntry {
+    pub path: String,
+    pub program: String,
+    pub category: String,
+    pub is_dir: bool,
+    pub bytes: u64,
+}
+#[derive(Clone, Copy, PartialEq)]
+pub enum CleanerSkipReason {
+    Symlink,
+    MountPoint,
+    ProgramRunning,
+    // not overwritten, other hard links still point to the content
+    HardLinked,
+}
+impl Display for CleanerSkipReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanerSkipReason::Symlink => write!(f, "symlink"),
+            CleanerSkipReason::MountPoint => write!(f, "mount point"),
+            CleanerSkipReason::ProgramRunning => write!(f, "program is running"),
+            CleanerSkipReason::HardLinked => write!(f, "hard linked, not overwritten"),
+        }
+    }
+}
+#[derive(Clone, Tabled)]
+pub struct CleanerSkip {
+    #[tabled(rename = "Path")]
+    pub path: String,
+    #[tabled(rename = "Reason")]
+    pub reason: CleanerSkipReason,
+}
+#[derive(Clone, Tabled)]
+pub struct CleanerLock {
+    #[tabled(rename = "Path")]
+    pub path: String,
+    // "1234 firefox, 1240 firefox", or "unknown process" when the system does not tell
+    #[tabled(rename = "Held by")]
+    pub holders: String,
 }
\ No newline at end of file
@@ -1,5 +1,8 @@
+use std::env;
+use std::path::PathBuf;
+
 pub fn get_file_size_string(size: u64) -> String {
-    if size <= 0 {
+    if size == 0 {
         return "0 B".to_string();
     }
 
@@ -8,4 +11,57 @@ pub fn get_file_size_string(size: u64) -> String {
 
     let size_in_units = size as f64 / 1024_f64.powi(digit_groups as i32);
     format!("{:.1} {}", size_in_units, units[digit_groups])
-}
\ No newline at end of file
+}
+
+pub fn get_winbooster_directory() -> PathBuf {
+    #[cfg(windows)]
+    let base = env::var("LOCALAPPDATA").map(PathBuf::from).unwrap_or_else(|_| env::temp_dir());
+    #[cfg(unix)]
+    let base = match env::var("XDG_DATA_HOME") {
+        Ok(data_home) => PathBuf::from(data_home),
+        Err(_) => {
+            let home = env::var("HOME").unwrap_or_else(|_| "/home/".to_owned() + &*whoami::username());
+            PathBuf::from(home).join(".local").join("share")
+        }
+    };
+    base.join("WinBooster")
+}
+
+pub fn format_timestamp(secs: u64) -> String {
+    // civil-from-days, UTC
+    let days = (secs / 86400) as i64;
+    let seconds_of_day = secs % 86400;
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year, month, day, seconds_of_day / 3600, seconds_of_day % 3600 / 60, seconds_of_day % 60
+    )
+}
+
+// The inverse of format_timestamp, None for anything format_timestamp does not produce
+pub fn parse_timestamp(text: &str) -> Option<u64> {
+    let (date, time) = text.split_once(' ')?;
+    let date: Vec<i64> = date.split('-').map(|part| part.parse().ok()).collect::<Option<_>>()?;
+    let time: Vec<u64> = time.split(':').map(|part| part.parse().ok()).collect::<Option<_>>()?;
+    let [year, month, day] = date[..] else { return None };
+    let [hour, minute, second] = time[..] else { return None };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    // days-from-civil, UTC
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = u64::try_from(era * 146097 + doe - 719468).ok()?;
+    Some(days * 86400 + hour * 3600 + minute * 60 + second)
+}
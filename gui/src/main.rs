@@ -1,20 +1,40 @@
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::Path;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 use eframe::egui;
 use indicatif::{ProgressBar, ProgressStyle};
 use notify_rust::Notification;
 use tabled::Table;
 use tokio::sync::mpsc;
 use tokio::task;
-use cleaner::clear_data;
-use database::{get_winbooster_version, registry_database};
-use database::structures::{CleanerData, CleanerResult, Cleared};
+use cleaner::{clear_plan, preview_plan, CleanerOptions};
+use cleaner::accounting::{SpaceAccounting, SpaceUsage};
+use cleaner::cancellation::CancellationToken;
+use cleaner::exclusions::{self, compile_patterns, load_exclusions};
+use cleaner::free_space::{FreeSpaceMonitor, MountSpace};
+use cleaner::journal::Journal;
+use cleaner::observer::{CleanerEvent, CleanerEventKind, CleanerObserver};
+use cleaner::plan::plan_rules;
+use cleaner::pool::{default_concurrency, fair_order, run_pool, PoolEvent};
+use cleaner::processes::{OpenFiles, RunningProgramPolicy};
+use cleaner::protection::load_protected_paths;
+use cleaner::shred::{DEFAULT_PASSES, SECURE_CATEGORIES};
+use cleaner::throttle::Throttle;
+use cleaner::quarantine::{self, Quarantine, QUARANTINE_EXPIRY_DAYS};
+use database::get_winbooster_version;
+#[cfg(windows)]
+use database::registry_database;
+use database::cleaner_database::load_database;
+use database::structures::{CleanerData, CleanerError, CleanerLock, CleanerResult, CleanerSkip, Cleared, PreviewEntry};
 use database::utils::get_file_size_string;
 
 #[tokio::main]
 async fn main() -> eframe::Result {
     env_logger::init();
+    quarantine::expire_runs(Duration::from_secs(QUARANTINE_EXPIRY_DAYS * 24 * 60 * 60));
 
     let options = eframe::NativeOptions {
         run_and_return: true,
@@ -23,7 +43,7 @@ async fn main() -> eframe::Result {
     };
 
     eframe::run_native(
-        &*("WinBooster Definitive Edition GUI v".to_owned() + &*get_winbooster_version()),
+        &("WinBooster Definitive Edition GUI v".to_owned() + &*get_winbooster_version()),
         options.clone(),
         Box::new(|_cc| {
             _cc.egui_ctx.set_visuals(egui::Visuals::dark());
@@ -32,12 +52,37 @@ async fn main() -> eframe::Result {
     )
 }
 
+// Передаёт в интерфейс путь, который сейчас очищается
+struct ProgressObserver {
+    ctx: egui::Context,
+    progress_sender: mpsc::Sender<String>,
+}
+
+impl CleanerObserver for ProgressObserver {
+    fn notify(&self, event: &CleanerEvent) {
+        match event.kind {
+            CleanerEventKind::Scanned => {
+                // Если канал переполнен, сообщение можно пропустить
+                let _ = self.progress_sender.try_send(event.path.to_string());
+                self.ctx.request_repaint();
+            }
+            CleanerEventKind::Waiting => {
+                let _ = self.progress_sender.try_send(format!("Waiting for {} to close...", event.path));
+                self.ctx.request_repaint();
+            }
+            _ => {}
+        }
+    }
+}
+
 async fn work(
     ctx: egui::Context,
     disabled_programs: Vec<&str>,
     categories: Vec<String>,
     database: Vec<CleanerData>,
+    mut options: CleanerOptions,
     progress_sender: mpsc::Sender<String>,
+    summary_sender: mpsc::Sender<WorkSummary>,
 ) {
     let sty = ProgressStyle::with_template(
         "[{elapsed_precise}] {prefix:.bold.dim} {spinner:.green}\n[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} [{msg}]",
@@ -47,10 +92,25 @@ async fn work(
     let mut removed_files = 0;
     let mut removed_directories = 0;
     let mut cleared_programs: Vec<Cleared> = vec![];
+    let mut errors: Vec<CleanerError> = vec![];
+    let mut skipped: Vec<CleanerSkip> = vec![];
+    let mut locked: Vec<CleanerLock> = vec![];
+    let mut overwritten_bytes = 0;
 
     let pb = ProgressBar::new(0);
     pb.set_style(sty.clone());
     pb.set_prefix("Clearing");
+    options.observer = Some(Arc::new(ProgressObserver {
+        ctx: ctx.clone(),
+        progress_sender: progress_sender.clone(),
+    }));
+    let category_names: Vec<&str> = categories.iter().map(|category| &**category).collect();
+    match Journal::create(&category_names, &disabled_programs, options.quarantine.as_ref().map(|quarantine| quarantine.run_id())) {
+        Ok(journal) => options.journal = Some(Arc::new(journal)),
+        Err(error) => eprintln!("Failed to create the run journal: {}", error),
+    }
+    let accounting = Arc::new(SpaceAccounting::default());
+    options.accounting = Some(accounting.clone());
 
     let mut threads = vec![];
 
@@ -71,81 +131,251 @@ async fn work(
                 working: false,
                 path: String::new(),
                 program: String::new(),
+                errors: vec![],
+                skipped: vec![],
+                overwritten_bytes: 0,
+                locked: vec![],
             }
         });
         threads.push(task);
     }
 
-    for data in database.iter() {
-        if categories.contains(&data.category) && !disabled_programs.contains(&data.program.as_str()) {
-            let data = Arc::new(data.clone());
-            let progress_bar = Arc::new(pb.clone());
-            let progress_sender = progress_sender.clone();
-            let ctx = ctx.clone();
+    let entries: Vec<CleanerData> = fair_order(database.into_iter()
+        .filter(|data| categories.contains(&data.category) && !disabled_programs.contains(&data.program.as_str()))
+        .collect());
+    pb.set_length((threads.len() + entries.len()) as u64);
 
-            let task = task::spawn(async move {
-                progress_bar.set_message(format!("{}", data.path.clone()));
-                progress_sender.send(format!("{}", data.path.clone())).await.unwrap();
-                ctx.request_repaint(); // Запрашиваем обновление UI
-                let result = clear_data(&data);
-                progress_bar.inc(1);
-                result
+    // Очистка идёт в отдельных потоках, чтобы не блокировать рантайм и интерфейс
+    let clear_task = {
+        let progress_bar = pb.clone();
+        let progress_sender = progress_sender.clone();
+        let ctx = ctx.clone();
+        let mut options = options.clone();
+        task::spawn_blocking(move || {
+            let mut results = vec![];
+            let _ = progress_sender.blocking_send("Planning...".to_string());
+            ctx.request_repaint();
+            let plans = plan_rules(&entries, &options);
+            options.open_files = Some(Arc::new(OpenFiles::snapshot()));
+            let free_space = FreeSpaceMonitor::sample(plans.iter().flat_map(|plan| &plan.paths).map(|path| Path::new(&path.path)));
+            run_pool(&plans, options.concurrency, |plan| clear_plan(plan, &options), |event| match event {
+                PoolEvent::Started(plan) => {
+                    progress_bar.set_message(plan.data.path.clone());
+                    let _ = progress_sender.blocking_send(plan.data.path.clone());
+                    ctx.request_repaint(); // Запрашиваем обновление UI
+                }
+                PoolEvent::Finished(_, result) => {
+                    progress_bar.inc(1);
+                    results.push(result);
+                }
             });
-            threads.push(task);
-        }
-    }
-
-    pb.set_length(threads.len() as u64);
+            (results, free_space)
+        })
+    };
 
+    let mut results = vec![];
     for task in threads {
         match task.await {
-            Ok(result) => {
-                removed_files += result.files;
-                removed_directories += result.folders;
-                bytes_cleared += result.bytes;
-                if result.working {
-                    let data2 = Cleared { Program: result.program };
-                    if !cleared_programs.contains(&data2) {
-                        cleared_programs.push(data2);
-                    }
-                }
-            },
+            Ok(result) => results.push(result),
             Err(_) => {
                 eprintln!("Error waiting for task completion");
             }
         }
     }
+    let mut free_space = None;
+    match clear_task.await {
+        Ok((pool_results, pool_free_space)) => {
+            results.extend(pool_results);
+            free_space = Some(pool_free_space);
+        }
+        Err(_) => {
+            eprintln!("Error waiting for task completion");
+        }
+    }
+
+    for result in results {
+        removed_files += result.files;
+        removed_directories += result.folders;
+        bytes_cleared += result.bytes;
+        errors.extend(result.errors);
+        skipped.extend(result.skipped);
+        locked.extend(result.locked);
+        overwritten_bytes += result.overwritten_bytes;
+        if result.working {
+            let data2 = Cleared { program: result.program };
+            if !cleared_programs.contains(&data2) {
+                cleared_programs.push(data2);
+            }
+        }
+    }
 
     pb.set_message("done");
     pb.finish();
 
-    progress_sender.send("Cleaning complete!".to_string()).await.unwrap();
+    let cancelled = options.cancellation.is_cancelled();
+    if cancelled {
+        progress_sender.send("Cleaning cancelled".to_string()).await.unwrap();
+    }
+    else {
+        progress_sender.send("Cleaning complete!".to_string()).await.unwrap();
+    }
 
+    if cancelled {
+        println!("Cancelled, partial summary:");
+    }
     println!("Cleared programs:");
     let table = Table::new(cleared_programs).to_string();
     println!("{}", table);
     println!("Removed: {}", get_file_size_string(bytes_cleared));
+    let usage = accounting.usage();
+    println!("Apparent size: {}", get_file_size_string(usage.apparent));
+    println!("Allocated size: {}", get_file_size_string(usage.allocated));
+    println!("Space actually reclaimed: {}", get_file_size_string(usage.reclaimed));
     println!("Removed files: {}", removed_files);
     println!("Removed directories: {}", removed_directories);
+    let mounts = free_space.map(FreeSpaceMonitor::finish).unwrap_or_default();
+    for space in &mounts {
+        println!("Free space on {}: {}", space.mount.display(), format_free_space(space));
+    }
+    if overwritten_bytes > 0 {
+        println!("Securely overwritten: {}", get_file_size_string(overwritten_bytes));
+    }
+    if !errors.is_empty() {
+        println!("Failed: {}", errors.len());
+        println!("{}", Table::new(errors.clone()));
+    }
+    if !skipped.is_empty() {
+        println!("Skipped: {}", skipped.len());
+        println!("{}", Table::new(skipped.clone()));
+    }
+    if !locked.is_empty() {
+        println!("Locked: {}", locked.len());
+        println!("{}", Table::new(locked.clone()));
+    }
 
     let _ = Notification::new()
         .summary("WinBooster CLI")
-        .body(&*("Removed: ".to_owned() + &*get_file_size_string(bytes_cleared) + "\nFiles: " + &*removed_files.to_string()))
+        .body(&("Removed: ".to_owned() + &*get_file_size_string(bytes_cleared) + "\nFiles: " + &*removed_files.to_string()))
         .icon("assets\\icon.png")
         .show();
+
+    if let Some(journal) = &options.journal {
+        let _ = journal.finish(removed_files, removed_directories, bytes_cleared, errors.len(), cancelled);
+    }
+
+    summary_sender.send(WorkSummary {
+        bytes: bytes_cleared,
+        files: removed_files,
+        folders: removed_directories,
+        errors,
+        skipped,
+        locked,
+        usage,
+        mounts,
+        quarantine_run: options.quarantine.as_ref().map(|quarantine| quarantine.run_id().to_string()),
+        journal_run: options.journal.as_ref().map(|journal| journal.run_id().to_string()),
+        cancelled,
+        overwritten_bytes,
+    }).await.unwrap();
+    ctx.request_repaint();
+}
+
+// Найденные пути, сгруппированные по программе и категории
+type PreviewGroups = BTreeMap<(String, String), Vec<PreviewEntry>>;
+
+async fn preview(
+    ctx: egui::Context,
+    disabled_programs: Vec<&str>,
+    categories: Vec<String>,
+    database: Vec<CleanerData>,
+    options: CleanerOptions,
+    preview_sender: mpsc::Sender<PreviewGroups>,
+) {
+    let rules: Vec<CleanerData> = fair_order(database.into_iter()
+        .filter(|data| categories.contains(&data.category) && !disabled_programs.contains(&data.program.as_str()))
+        .collect());
+
+    let preview_task = task::spawn_blocking(move || {
+        let mut groups: PreviewGroups = BTreeMap::new();
+        let plans = plan_rules(&rules, &options);
+        run_pool(&plans, options.concurrency, |plan| preview_plan(plan, &options), |event| {
+            if let PoolEvent::Finished(_, entries) = event {
+                for entry in entries {
+                    groups.entry((entry.program.clone(), entry.category.clone())).or_default().push(entry);
+                }
+            }
+        });
+        groups
+    });
+    let groups = match preview_task.await {
+        Ok(groups) => groups,
+        Err(_) => {
+            eprintln!("Error waiting for task completion");
+            BTreeMap::new()
+        }
+    };
+
+    preview_sender.send(groups).await.unwrap();
+    ctx.request_repaint();
+}
+
+// "10 GB → 12 GB (+2 GB)"
+fn format_free_space(space: &MountSpace) -> String {
+    let format_free = |free: Option<u64>| match free {
+        Some(free) => get_file_size_string(free),
+        None => "unknown".to_string(),
+    };
+    let freed = match space.freed() {
+        Some(freed) if freed < 0 => format!(" (-{})", get_file_size_string(freed.unsigned_abs() as u64)),
+        Some(freed) => format!(" (+{})", get_file_size_string(freed as u64)),
+        None => String::new(),
+    };
+    format!("{} → {}{}", format_free(space.before), format_free(space.after), freed)
+}
+
+struct WorkSummary {
+    pub(crate) bytes: u64,
+    pub(crate) files: u64,
+    pub(crate) folders: u64,
+    pub(crate) errors: Vec<CleanerError>,
+    pub(crate) skipped: Vec<CleanerSkip>,
+    pub(crate) locked: Vec<CleanerLock>, // Файлы, открытые другими процессами
+    pub(crate) usage: SpaceUsage, // Размер с учётом жёстких ссылок и занятых блоков
+    pub(crate) mounts: Vec<MountSpace>, // Свободное место на каждом затронутом диске до и после очистки
+    pub(crate) quarantine_run: Option<String>,
+    pub(crate) journal_run: Option<String>, // Журнал запуска, см. "history" в CLI
+    pub(crate) cancelled: bool,
+    pub(crate) overwritten_bytes: u64,
 }
 
 struct MyApp {
     pub(crate) checked_boxes: Vec<(Rc<RefCell<bool>>, String)>,
-    pub(crate) selected_options: Vec<String>,
     pub(crate) task_handle: Option<tokio::task::JoinHandle<()>>,
+    pub(crate) cancellation: Option<CancellationToken>, // Отмена текущей задачи
     pub(crate) progress_message: String, // Сообщение о прогрессе
     pub(crate) progress_receiver: Option<mpsc::Receiver<String>>, // Канал для получения сообщений о прогрессе
+    pub(crate) preview_receiver: Option<mpsc::Receiver<PreviewGroups>>,
+    pub(crate) preview: Option<PreviewGroups>, // Результат предпросмотра
+    pub(crate) summary_receiver: Option<mpsc::Receiver<WorkSummary>>,
+    pub(crate) summary: Option<WorkSummary>, // Итоги последней очистки
+    pub(crate) quarantine: bool, // Перемещать файлы в карантин вместо удаления
+    pub(crate) exclusions: Vec<String>, // Глобальные исключения
+    pub(crate) new_exclusion: String,
+    pub(crate) concurrency: usize, // Количество потоков очистки
+    pub(crate) secure_categories: Vec<(String, bool)>, // Категории с безопасной перезаписью
+    pub(crate) secure_passes: u32,
+    pub(crate) running_programs: RunningProgramPolicy, // Что делать с запущенными программами
+    pub(crate) prune_empty_directories: bool, // Удалять опустевшие папки
+    pub(crate) background: bool, // Фоновый режим: ограничение скорости и низкий приоритет
+    pub(crate) rule_errors: Vec<String>, // Файлы правил, которые не удалось загрузить
+    pub(crate) database: Vec<CleanerData>, // Правила, загруженные при запуске, их же и очищаем
 }
 
 impl MyApp {
     pub(crate) fn new() -> Self {
-        let database: Vec<CleanerData> = database::cleaner_database::get_database();
+        let loaded = load_database(&[]);
+        let rule_errors: Vec<String> = loaded.errors.iter().map(|error| error.to_string()).collect();
+        let database: Vec<CleanerData> = loaded.rules;
 
         let mut options: Vec<String> = vec![];
         for data in database.iter() {
@@ -161,11 +391,52 @@ impl MyApp {
 
         Self {
             checked_boxes,
-            selected_options: vec![],
             task_handle: None,
+            cancellation: None,
             progress_message: String::new(),
             progress_receiver: None,
+            preview_receiver: None,
+            preview: None,
+            summary_receiver: None,
+            summary: None,
+            quarantine: false,
+            exclusions: load_exclusions(),
+            new_exclusion: String::new(),
+            concurrency: default_concurrency(),
+            secure_categories: SECURE_CATEGORIES.iter().map(|category| (category.to_string(), false)).collect(),
+            secure_passes: DEFAULT_PASSES,
+            running_programs: RunningProgramPolicy::Skip,
+            prune_empty_directories: false,
+            background: false,
+            rule_errors,
+            database,
+        }
+    }
+
+    fn create_options(&self, use_quarantine: bool) -> Result<CleanerOptions, String> {
+        let mut options = CleanerOptions {
+            concurrency: self.concurrency,
+            running_programs: self.running_programs,
+            prune_empty_directories: self.prune_empty_directories,
+            ..CleanerOptions::default()
+        };
+        if self.background {
+            options.throttle = Some(Arc::new(Throttle::background()));
+        }
+        for (category, enabled) in &self.secure_categories {
+            if *enabled {
+                options.secure_overwrite.insert(category.clone(), self.secure_passes);
+            }
+        }
+        options.exclusions = compile_patterns(&self.exclusions)
+            .map_err(|pattern| format!("Invalid exclusion pattern: {}", pattern))?;
+        options.protected_paths = load_protected_paths();
+        if use_quarantine {
+            let quarantine = Quarantine::create()
+                .map_err(|error| format!("Failed to create quarantine: {}", error))?;
+            options.quarantine = Some(Arc::new(quarantine));
         }
+        Ok(options)
     }
 }
 
@@ -173,46 +444,168 @@ impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Проверяем, есть ли новые сообщения о прогрессе
         if let Some(receiver) = &mut self.progress_receiver {
-            if let Ok(message) = receiver.try_recv() {
+            while let Ok(message) = receiver.try_recv() {
                 self.progress_message = message;
                 ctx.request_repaint(); // Запрашиваем обновление UI
             }
         }
+        if let Some(receiver) = &mut self.preview_receiver && let Ok(preview) = receiver.try_recv() {
+            self.preview = Some(preview);
+            self.preview_receiver = None;
+            self.progress_message.clear();
+            self.task_handle = None;
+            self.cancellation = None;
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(640.0, 480.0)));
+        }
+        if let Some(receiver) = &mut self.summary_receiver && let Ok(summary) = receiver.try_recv() {
+            if !summary.errors.is_empty() || !summary.skipped.is_empty() || !summary.locked.is_empty() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(640.0, 480.0)));
+            }
+            self.summary = Some(summary);
+            self.summary_receiver = None;
+        }
 
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.columns(3, |columns| {
                 for (i, (checkbox, label)) in self.checked_boxes.iter().enumerate() {
                     let column_index = i % 3;
                     let mut value = checkbox.borrow_mut();
-                    columns[column_index].checkbox(&mut *value, label);
+                    columns[column_index].checkbox(&mut value, label);
                 }
             });
 
-            if let Some(handle) = &self.task_handle {
+            if !self.rule_errors.is_empty() {
+                egui::CollapsingHeader::new(format!("Failed to load rule files: {}", self.rule_errors.len()))
+                    .show(ui, |ui| {
+                        for error in &self.rule_errors {
+                            ui.label(error);
+                        }
+                    });
+            }
+            if !self.progress_message.is_empty() {
                 ui.label(&self.progress_message);
-                if handle.is_finished() {
-                    self.task_handle = None;
-                }
+            }
+            if let Some(handle) = &self.task_handle && handle.is_finished() {
+                self.task_handle = None;
+                self.cancellation = None;
+            }
+            if let Some(cancellation) = &self.cancellation
+                && !cancellation.is_cancelled() && ui.add_sized([ui.available_width(), 25.0], egui::Button::new("Cancel")).clicked() {
+                cancellation.cancel();
+                self.progress_message = "Cancelling...".to_string();
             }
 
             if self.task_handle.is_none() {
                 let available_width = ui.available_width();
+                let mut selected_options = vec![];
+                for (checkbox, label) in &self.checked_boxes {
+                    if *checkbox.borrow() {
+                        selected_options.push(label.clone());
+                    }
+                }
+
+                ui.checkbox(&mut self.quarantine, "Move to quarantine instead of deleting");
+                ui.add_enabled(!self.quarantine, egui::Checkbox::new(&mut self.prune_empty_directories, "Remove directories left empty"))
+                    .on_disabled_hover_text("Not available with quarantine, restoring could not bring the directories back");
+                ui.checkbox(&mut self.background, "Background mode (slower, keeps the system responsive)");
+                ui.horizontal(|ui| {
+                    ui.label("Worker threads:");
+                    ui.add(egui::DragValue::new(&mut self.concurrency).range(1..=64));
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Running programs:");
+                    ui.radio_value(&mut self.running_programs, RunningProgramPolicy::Skip, "Skip");
+                    ui.radio_value(&mut self.running_programs, RunningProgramPolicy::Wait, "Wait until closed");
+                    ui.radio_value(&mut self.running_programs, RunningProgramPolicy::Ignore, "Clean anyway");
+                });
+
+                egui::CollapsingHeader::new("Secure overwrite").show(ui, |ui| {
+                    for (category, enabled) in self.secure_categories.iter_mut() {
+                        ui.checkbox(enabled, category.as_str());
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Passes:");
+                        ui.add(egui::DragValue::new(&mut self.secure_passes).range(1..=35));
+                    });
+                });
 
-                if ui.add_sized([available_width, 25.0], egui::Button::new("Clear")).clicked() {
-                    let mut selected_options = vec![];
-                    for (checkbox, label) in &self.checked_boxes {
-                        if *checkbox.borrow() {
-                            selected_options.push(label.clone());
+                egui::CollapsingHeader::new(format!("Exclusions ({})", self.exclusions.len())).show(ui, |ui| {
+                    let mut removed = None;
+                    for pattern in &self.exclusions {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("Remove").clicked() {
+                                removed = Some(pattern.clone());
+                            }
+                            ui.label(pattern);
+                        });
+                    }
+                    if let Some(pattern) = removed {
+                        if let Err(error) = exclusions::remove_exclusion(&pattern) {
+                            self.progress_message = format!("Failed to remove exclusion: {}", error);
                         }
+                        self.exclusions = load_exclusions();
                     }
 
-                    let database: Vec<CleanerData> = database::cleaner_database::get_database();
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut self.new_exclusion);
+                        if ui.button("Add").clicked() && !self.new_exclusion.trim().is_empty() {
+                            match exclusions::add_exclusion(self.new_exclusion.trim()) {
+                                Ok(_) => self.new_exclusion.clear(),
+                                Err(error) => self.progress_message = format!("Failed to add exclusion: {}", error),
+                            }
+                            self.exclusions = load_exclusions();
+                        }
+                    });
+                });
+
+                let (clear_clicked, preview_clicked) = ui.horizontal(|ui| {
+                    let button_width = (available_width - ui.spacing().item_spacing.x) / 2.0;
+                    let clear = ui.add_sized([button_width, 25.0], egui::Button::new("Clear")).clicked();
+                    let preview = ui.add_sized([button_width, 25.0], egui::Button::new("Preview")).clicked();
+                    (clear, preview)
+                }).inner;
+
+                if preview_clicked {
+                    let database: Vec<CleanerData> = self.database.clone();
+                    let options = match self.create_options(false) {
+                        Ok(options) => options,
+                        Err(error) => {
+                            self.progress_message = error;
+                            return;
+                        }
+                    };
+
+                    let (preview_sender, preview_receiver) = mpsc::channel(1);
+                    self.preview_receiver = Some(preview_receiver);
+                    self.progress_message = "Preview...".to_string();
+
+                    self.cancellation = Some(options.cancellation.clone());
+                    let ctx = ctx.clone();
+                    let handle = tokio::spawn(preview(ctx, vec![], selected_options, database, options, preview_sender));
+                    self.task_handle = Some(handle);
+                }
+                else if clear_clicked {
+                    self.preview = None;
+                    self.summary = None;
+                    let database: Vec<CleanerData> = self.database.clone();
+
+                    let options = match self.create_options(self.quarantine) {
+                        Ok(options) => options,
+                        Err(error) => {
+                            self.progress_message = error;
+                            return;
+                        }
+                    };
 
                     let (progress_sender, progress_receiver) = mpsc::channel(32);
                     self.progress_receiver = Some(progress_receiver);
+                    let (summary_sender, summary_receiver) = mpsc::channel(1);
+                    self.summary_receiver = Some(summary_receiver);
 
+                    self.cancellation = Some(options.cancellation.clone());
                     let ctx = ctx.clone();
-                    let handle = tokio::spawn(work(ctx, vec![], selected_options, database, progress_sender));
+                    let handle = tokio::spawn(work(ctx, vec![], selected_options, database, options, progress_sender, summary_sender));
                     self.task_handle = Some(handle);
 
                     // Сбрасываем все чекбоксы
@@ -221,6 +614,83 @@ impl eframe::App for MyApp {
                     }
                 }
             }
+
+            if let Some(summary) = &self.summary {
+                ui.separator();
+                if summary.cancelled {
+                    ui.label("Cancelled, partial result:");
+                }
+                ui.label(format!(
+                    "Removed: {}, files: {}, directories: {}",
+                    get_file_size_string(summary.bytes), summary.files, summary.folders
+                ));
+                ui.label(format!(
+                    "Apparent size: {}, allocated: {}, actually reclaimed: {}",
+                    get_file_size_string(summary.usage.apparent),
+                    get_file_size_string(summary.usage.allocated),
+                    get_file_size_string(summary.usage.reclaimed)
+                ));
+                for space in &summary.mounts {
+                    ui.label(format!("Free space on {}: {}", space.mount.display(), format_free_space(space)));
+                }
+                if summary.overwritten_bytes > 0 {
+                    ui.label(format!("Securely overwritten: {}", get_file_size_string(summary.overwritten_bytes)));
+                }
+                if let Some(quarantine_run) = &summary.quarantine_run {
+                    ui.label(format!("Quarantine run: {}", quarantine_run));
+                }
+                if let Some(journal_run) = &summary.journal_run {
+                    ui.label(format!("Journal: {}", journal_run));
+                }
+                if !summary.errors.is_empty() {
+                    egui::CollapsingHeader::new(format!("Failed: {}", summary.errors.len()))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            egui::ScrollArea::vertical().show(ui, |ui| {
+                                for error in &summary.errors {
+                                    ui.label(format!("{} ({}: {})", error.path, error.operation, error.kind));
+                                }
+                            });
+                        });
+                }
+                if !summary.skipped.is_empty() {
+                    egui::CollapsingHeader::new(format!("Skipped: {}", summary.skipped.len()))
+                        .show(ui, |ui| {
+                            egui::ScrollArea::vertical().id_salt("skipped").show(ui, |ui| {
+                                for skip in &summary.skipped {
+                                    ui.label(format!("{} ({})", skip.path, skip.reason));
+                                }
+                            });
+                        });
+                }
+                if !summary.locked.is_empty() {
+                    egui::CollapsingHeader::new(format!("Locked: {}", summary.locked.len()))
+                        .show(ui, |ui| {
+                            egui::ScrollArea::vertical().id_salt("locked").show(ui, |ui| {
+                                for lock in &summary.locked {
+                                    ui.label(format!("{} (held by {})", lock.path, lock.holders));
+                                }
+                            });
+                        });
+                }
+            }
+
+            if let Some(preview) = &self.preview {
+                let total_bytes: u64 = preview.values().flatten().map(|entry| entry.bytes).sum();
+                ui.separator();
+                ui.label(format!("Would remove: {}", get_file_size_string(total_bytes)));
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for ((program, category), entries) in preview {
+                        let group_bytes: u64 = entries.iter().map(|entry| entry.bytes).sum();
+                        egui::CollapsingHeader::new(format!("{} / {} ({})", program, category, get_file_size_string(group_bytes)))
+                            .show(ui, |ui| {
+                                for entry in entries {
+                                    ui.label(format!("{} ({})", entry.path, get_file_size_string(entry.bytes)));
+                                }
+                            });
+                    }
+                });
+            }
         });
     }
 }
\ No newline at end of file